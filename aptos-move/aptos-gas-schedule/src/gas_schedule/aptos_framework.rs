@@ -5,7 +5,7 @@
 
 use crate::{
     gas_schedule::NativeGasParameters,
-    ver::gas_feature_versions::{RELEASE_V1_12, RELEASE_V1_13},
+    ver::gas_feature_versions::{RELEASE_V1_12, RELEASE_V1_13, RELEASE_V1_14},
 };
 use aptos_gas_algebra::{
     InternalGas, InternalGasPerAbstractValueUnit, InternalGasPerArg, InternalGasPerByte,
@@ -94,6 +94,231 @@ crate::gas_schedule::macros::define_gas_parameters!(
         [algebra_ark_bn254_multi_pairing_per_pair: InternalGasPerArg, { 12.. => "algebra.ark_bn254_multi_pairing_per_pair" }, 12429399],
         [algebra_ark_bn254_pairing: InternalGas, { 12.. => "algebra.ark_bn254_pairing" }, 38543565],
         // BN254 algebra gas parameters end.
+        // From the `ark_bn254/fq12_cyclotomic_exp_u64` bench (425_808ns, subgroup check and the densest `u64` exponent included)
+        // at gas_per_ns=23.98, calibrated against the BN254 parameters above like `update_prime_order_curves_gas_params.py`.
+        [algebra_ark_bn254_fq12_cyclotomic_pow_u64: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_fq12_cyclotomic_pow_u64" }, 10_211_319],
+        // Returns a precomputed constant, like `algebra_ark_bn254_fr_zero`.
+        [algebra_ark_bn254_modulus: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_modulus" }, 38],
+        // A SHA2-512 hash followed by a wide reduction into `Fr`, estimated as an `fr_deser` and 2 `fr_mul`.
+        [algebra_ark_bn254_fr_from_transcript_base: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bn254_fr_from_transcript_base" }, 18609], // 11_910 + 3_073 + 2 * 1_813
+        [algebra_ark_bn254_fr_from_transcript_per_byte: InternalGasPerByte, { RELEASE_V1_14.. => "algebra.ark_bn254_fr_from_transcript_per_byte" }, 220],
+
+        // secp256k1 algebra gas parameters begin.
+        // Generated at time 1792140758.5371597 by `scripts/algebra-gas/update_prime_order_curves_gas_params.py` with gas_per_ns=23.98103011599866.
+        [algebra_ark_secp256k1_fq_add: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fq_add" }, 1_068],
+        [algebra_ark_secp256k1_fq_deser: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fq_deser" }, 2_888],
+        [algebra_ark_secp256k1_fq_div: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fq_div" }, 112_228],
+        [algebra_ark_secp256k1_fq_eq: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fq_eq" }, 903],
+        [algebra_ark_secp256k1_fq_from_u64: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fq_from_u64" }, 2_007],
+        [algebra_ark_secp256k1_fq_inv: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fq_inv" }, 111_189],
+        [algebra_ark_secp256k1_fq_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fq_mul" }, 1_889],
+        [algebra_ark_secp256k1_fq_neg: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fq_neg" }, 1_148],
+        [algebra_ark_secp256k1_fq_one: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fq_one" }, 23],
+        [algebra_ark_secp256k1_fq_serialize: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fq_serialize" }, 6_252],
+        [algebra_ark_secp256k1_fq_square: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fq_square" }, 1_097],
+        [algebra_ark_secp256k1_fq_sub: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fq_sub" }, 1_096],
+        [algebra_ark_secp256k1_fq_zero: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fq_zero" }, 23],
+        [algebra_ark_secp256k1_fr_add: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fr_add" }, 837],
+        [algebra_ark_secp256k1_fr_deser: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fr_deser" }, 2_550],
+        [algebra_ark_secp256k1_fr_div: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fr_div" }, 116_790],
+        [algebra_ark_secp256k1_fr_eq: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fr_eq" }, 1_148],
+        [algebra_ark_secp256k1_fr_from_u64: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fr_from_u64" }, 2_289],
+        [algebra_ark_secp256k1_fr_inv: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fr_inv" }, 111_843],
+        [algebra_ark_secp256k1_fr_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fr_mul" }, 2_356],
+        [algebra_ark_secp256k1_fr_neg: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fr_neg" }, 1_176],
+        [algebra_ark_secp256k1_fr_one: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fr_one" }, 23],
+        [algebra_ark_secp256k1_fr_serialize: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fr_serialize" }, 6_112],
+        [algebra_ark_secp256k1_fr_square: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fr_square" }, 1_141],
+        [algebra_ark_secp256k1_fr_sub: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fr_sub" }, 1_132],
+        [algebra_ark_secp256k1_fr_zero: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_fr_zero" }, 23],
+        [algebra_ark_secp256k1_g1_affine_deser_comp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_g1_affine_deser_comp" }, 538_244],
+        [algebra_ark_secp256k1_g1_affine_deser_uncomp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_g1_affine_deser_uncomp" }, 10_808],
+        [algebra_ark_secp256k1_g1_affine_serialize_comp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_g1_affine_serialize_comp" }, 4_530],
+        [algebra_ark_secp256k1_g1_affine_serialize_uncomp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_g1_affine_serialize_uncomp" }, 6_382],
+        [algebra_ark_secp256k1_g1_proj_add: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_g1_proj_add" }, 14_874],
+        [algebra_ark_secp256k1_g1_proj_double: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_g1_proj_double" }, 11_412],
+        [algebra_ark_secp256k1_g1_proj_eq: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_g1_proj_eq" }, 11_403],
+        [algebra_ark_secp256k1_g1_proj_generator: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_g1_proj_generator" }, 23],
+        [algebra_ark_secp256k1_g1_proj_infinity: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_g1_proj_infinity" }, 23],
+        [algebra_ark_secp256k1_g1_proj_neg: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_g1_proj_neg" }, 23],
+        [algebra_ark_secp256k1_g1_proj_scalar_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_g1_proj_scalar_mul" }, 2_778_025],
+        [algebra_ark_secp256k1_g1_proj_sub: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_g1_proj_sub" }, 22_978],
+        [algebra_ark_secp256k1_g1_proj_to_affine: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256k1_g1_proj_to_affine" }, 1_927],
+        // secp256k1 algebra gas parameters end.
+
+        // secp256r1 algebra gas parameters begin.
+        // Generated at time 1792140758.5395257 by `scripts/algebra-gas/update_prime_order_curves_gas_params.py` with gas_per_ns=23.98103011599866.
+        [algebra_ark_secp256r1_fq_add: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fq_add" }, 1_071],
+        [algebra_ark_secp256r1_fq_deser: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fq_deser" }, 3_003],
+        [algebra_ark_secp256r1_fq_div: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fq_div" }, 85_080],
+        [algebra_ark_secp256r1_fq_eq: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fq_eq" }, 821],
+        [algebra_ark_secp256r1_fq_from_u64: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fq_from_u64" }, 2_295],
+        [algebra_ark_secp256r1_fq_inv: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fq_inv" }, 70_673],
+        [algebra_ark_secp256r1_fq_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fq_mul" }, 1_473],
+        [algebra_ark_secp256r1_fq_neg: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fq_neg" }, 786],
+        [algebra_ark_secp256r1_fq_one: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fq_one" }, 23],
+        [algebra_ark_secp256r1_fq_serialize: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fq_serialize" }, 3_646],
+        [algebra_ark_secp256r1_fq_square: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fq_square" }, 1_305],
+        [algebra_ark_secp256r1_fq_sub: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fq_sub" }, 957],
+        [algebra_ark_secp256r1_fq_zero: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fq_zero" }, 23],
+        [algebra_ark_secp256r1_fr_add: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fr_add" }, 1_109],
+        [algebra_ark_secp256r1_fr_deser: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fr_deser" }, 3_647],
+        [algebra_ark_secp256r1_fr_div: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fr_div" }, 91_907],
+        [algebra_ark_secp256r1_fr_eq: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fr_eq" }, 936],
+        [algebra_ark_secp256r1_fr_from_u64: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fr_from_u64" }, 2_275],
+        [algebra_ark_secp256r1_fr_inv: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fr_inv" }, 69_121],
+        [algebra_ark_secp256r1_fr_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fr_mul" }, 1_748],
+        [algebra_ark_secp256r1_fr_neg: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fr_neg" }, 888],
+        [algebra_ark_secp256r1_fr_one: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fr_one" }, 23],
+        [algebra_ark_secp256r1_fr_serialize: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fr_serialize" }, 6_121],
+        [algebra_ark_secp256r1_fr_square: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fr_square" }, 1_078],
+        [algebra_ark_secp256r1_fr_sub: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fr_sub" }, 1_051],
+        [algebra_ark_secp256r1_fr_zero: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_fr_zero" }, 23],
+        [algebra_ark_secp256r1_g1_affine_deser_comp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_g1_affine_deser_comp" }, 234_518],
+        [algebra_ark_secp256r1_g1_affine_deser_uncomp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_g1_affine_deser_uncomp" }, 13_263],
+        [algebra_ark_secp256r1_g1_affine_serialize_comp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_g1_affine_serialize_comp" }, 5_384],
+        [algebra_ark_secp256r1_g1_affine_serialize_uncomp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_g1_affine_serialize_uncomp" }, 6_539],
+        [algebra_ark_secp256r1_g1_proj_add: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_g1_proj_add" }, 12_393],
+        [algebra_ark_secp256r1_g1_proj_double: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_g1_proj_double" }, 15_684],
+        [algebra_ark_secp256r1_g1_proj_eq: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_g1_proj_eq" }, 5_450],
+        [algebra_ark_secp256r1_g1_proj_generator: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_g1_proj_generator" }, 23],
+        [algebra_ark_secp256r1_g1_proj_infinity: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_g1_proj_infinity" }, 23],
+        [algebra_ark_secp256r1_g1_proj_neg: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_g1_proj_neg" }, 23],
+        [algebra_ark_secp256r1_g1_proj_scalar_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_g1_proj_scalar_mul" }, 3_697_147],
+        [algebra_ark_secp256r1_g1_proj_sub: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_g1_proj_sub" }, 20_104],
+        [algebra_ark_secp256r1_g1_proj_to_affine: InternalGas, { RELEASE_V1_14.. => "algebra.ark_secp256r1_g1_proj_to_affine" }, 1_447],
+        // secp256r1 algebra gas parameters end.
+
+        // Grumpkin algebra gas parameters begin.
+        // Generated at time 1792138779.745145 by `scripts/algebra-gas/update_prime_order_curves_gas_params.py` with gas_per_ns=23.98103011599866.
+        // Grumpkin `Fq` and `Fr` are the BN254 `Fr` and `Fq` types, so their values are reused as is.
+        [algebra_ark_grumpkin_fq_add: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fq_add" }, 804],
+        [algebra_ark_grumpkin_fq_deser: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fq_deser" }, 3_073],
+        [algebra_ark_grumpkin_fq_div: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fq_div" }, 223_857],
+        [algebra_ark_grumpkin_fq_eq: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fq_eq" }, 807],
+        [algebra_ark_grumpkin_fq_from_u64: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fq_from_u64" }, 2_478],
+        [algebra_ark_grumpkin_fq_inv: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fq_inv" }, 222_216],
+        [algebra_ark_grumpkin_fq_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fq_mul" }, 1_813],
+        [algebra_ark_grumpkin_fq_neg: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fq_neg" }, 792],
+        [algebra_ark_grumpkin_fq_one: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fq_one" }, 0],
+        [algebra_ark_grumpkin_fq_serialize: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fq_serialize" }, 4_732],
+        [algebra_ark_grumpkin_fq_square: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fq_square" }, 792],
+        [algebra_ark_grumpkin_fq_sub: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fq_sub" }, 1_906],
+        [algebra_ark_grumpkin_fq_zero: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fq_zero" }, 38],
+        [algebra_ark_grumpkin_fr_add: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fr_add" }, 803],
+        [algebra_ark_grumpkin_fr_deser: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fr_deser" }, 3_232],
+        [algebra_ark_grumpkin_fr_div: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fr_div" }, 209_631],
+        [algebra_ark_grumpkin_fr_eq: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fr_eq" }, 803],
+        [algebra_ark_grumpkin_fr_from_u64: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fr_from_u64" }, 2_598],
+        [algebra_ark_grumpkin_fr_inv: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fr_inv" }, 208_902],
+        [algebra_ark_grumpkin_fr_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fr_mul" }, 1_847],
+        [algebra_ark_grumpkin_fr_neg: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fr_neg" }, 792],
+        [algebra_ark_grumpkin_fr_one: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fr_one" }, 38],
+        [algebra_ark_grumpkin_fr_serialize: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fr_serialize" }, 4_767],
+        [algebra_ark_grumpkin_fr_square: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fr_square" }, 792],
+        [algebra_ark_grumpkin_fr_sub: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fr_sub" }, 1_130],
+        [algebra_ark_grumpkin_fr_zero: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_fr_zero" }, 38],
+        [algebra_ark_grumpkin_g1_affine_deser_comp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_g1_affine_deser_comp" }, 2_723_861],
+        [algebra_ark_grumpkin_g1_affine_deser_uncomp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_g1_affine_deser_uncomp" }, 3_369_054],
+        [algebra_ark_grumpkin_g1_affine_serialize_comp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_g1_affine_serialize_comp" }, 5_065],
+        [algebra_ark_grumpkin_g1_affine_serialize_uncomp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_g1_affine_serialize_uncomp" }, 6_671],
+        [algebra_ark_grumpkin_g1_proj_add: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_g1_proj_add" }, 12_275],
+        [algebra_ark_grumpkin_g1_proj_double: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_g1_proj_double" }, 6_736],
+        [algebra_ark_grumpkin_g1_proj_eq: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_g1_proj_eq" }, 6_413],
+        [algebra_ark_grumpkin_g1_proj_generator: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_g1_proj_generator" }, 23],
+        [algebra_ark_grumpkin_g1_proj_infinity: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_g1_proj_infinity" }, 23],
+        [algebra_ark_grumpkin_g1_proj_neg: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_g1_proj_neg" }, 23],
+        [algebra_ark_grumpkin_g1_proj_scalar_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_g1_proj_scalar_mul" }, 5_134_870],
+        [algebra_ark_grumpkin_g1_proj_sub: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_g1_proj_sub" }, 15_324],
+        [algebra_ark_grumpkin_g1_proj_to_affine: InternalGas, { RELEASE_V1_14.. => "algebra.ark_grumpkin_g1_proj_to_affine" }, 1_071],
+        // Grumpkin algebra gas parameters end.
+
+        // Pallas algebra gas parameters begin.
+        // Generated at time 1792138798.2136958 by `scripts/algebra-gas/update_prime_order_curves_gas_params.py` with gas_per_ns=23.98103011599866.
+        [algebra_ark_pallas_fq_add: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fq_add" }, 787],
+        [algebra_ark_pallas_fq_deser: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fq_deser" }, 3_066],
+        [algebra_ark_pallas_fq_div: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fq_div" }, 72_438],
+        [algebra_ark_pallas_fq_eq: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fq_eq" }, 796],
+        [algebra_ark_pallas_fq_from_u64: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fq_from_u64" }, 1_563],
+        [algebra_ark_pallas_fq_inv: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fq_inv" }, 69_543],
+        [algebra_ark_pallas_fq_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fq_mul" }, 1_402],
+        [algebra_ark_pallas_fq_neg: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fq_neg" }, 903],
+        [algebra_ark_pallas_fq_one: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fq_one" }, 23],
+        [algebra_ark_pallas_fq_serialize: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fq_serialize" }, 4_876],
+        [algebra_ark_pallas_fq_square: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fq_square" }, 1_050],
+        [algebra_ark_pallas_fq_sub: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fq_sub" }, 802],
+        [algebra_ark_pallas_fq_zero: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fq_zero" }, 23],
+        [algebra_ark_pallas_fr_add: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fr_add" }, 815],
+        [algebra_ark_pallas_fr_deser: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fr_deser" }, 1_991],
+        [algebra_ark_pallas_fr_div: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fr_div" }, 70_574],
+        [algebra_ark_pallas_fr_eq: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fr_eq" }, 922],
+        [algebra_ark_pallas_fr_from_u64: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fr_from_u64" }, 1_744],
+        [algebra_ark_pallas_fr_inv: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fr_inv" }, 76_580],
+        [algebra_ark_pallas_fr_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fr_mul" }, 2_450],
+        [algebra_ark_pallas_fr_neg: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fr_neg" }, 1_152],
+        [algebra_ark_pallas_fr_one: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fr_one" }, 23],
+        [algebra_ark_pallas_fr_serialize: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fr_serialize" }, 6_620],
+        [algebra_ark_pallas_fr_square: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fr_square" }, 1_174],
+        [algebra_ark_pallas_fr_sub: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fr_sub" }, 1_191],
+        [algebra_ark_pallas_fr_zero: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_fr_zero" }, 23],
+        [algebra_ark_pallas_g1_affine_deser_comp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_g1_affine_deser_comp" }, 514_677],
+        [algebra_ark_pallas_g1_affine_deser_uncomp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_g1_affine_deser_uncomp" }, 4_753],
+        [algebra_ark_pallas_g1_affine_serialize_comp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_g1_affine_serialize_comp" }, 3_465],
+        [algebra_ark_pallas_g1_affine_serialize_uncomp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_g1_affine_serialize_uncomp" }, 4_199],
+        [algebra_ark_pallas_g1_proj_add: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_g1_proj_add" }, 10_785],
+        [algebra_ark_pallas_g1_proj_double: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_g1_proj_double" }, 8_471],
+        [algebra_ark_pallas_g1_proj_eq: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_g1_proj_eq" }, 6_924],
+        [algebra_ark_pallas_g1_proj_generator: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_g1_proj_generator" }, 23],
+        [algebra_ark_pallas_g1_proj_infinity: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_g1_proj_infinity" }, 23],
+        [algebra_ark_pallas_g1_proj_neg: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_g1_proj_neg" }, 23],
+        [algebra_ark_pallas_g1_proj_scalar_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_g1_proj_scalar_mul" }, 4_616_652],
+        [algebra_ark_pallas_g1_proj_sub: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_g1_proj_sub" }, 11_645],
+        [algebra_ark_pallas_g1_proj_to_affine: InternalGas, { RELEASE_V1_14.. => "algebra.ark_pallas_g1_proj_to_affine" }, 1_107],
+        // Pallas algebra gas parameters end.
+
+        // Vesta algebra gas parameters begin.
+        // Generated at time 1792138798.2149196 by `scripts/algebra-gas/update_prime_order_curves_gas_params.py` with gas_per_ns=23.98103011599866.
+        // Vesta `Fq` and `Fr` are the Pallas `Fr` and `Fq` fields, so they are generated from the `ark_pallas` benches.
+        [algebra_ark_vesta_fq_add: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fq_add" }, 815],
+        [algebra_ark_vesta_fq_deser: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fq_deser" }, 1_991],
+        [algebra_ark_vesta_fq_div: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fq_div" }, 70_574],
+        [algebra_ark_vesta_fq_eq: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fq_eq" }, 922],
+        [algebra_ark_vesta_fq_from_u64: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fq_from_u64" }, 1_744],
+        [algebra_ark_vesta_fq_inv: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fq_inv" }, 76_580],
+        [algebra_ark_vesta_fq_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fq_mul" }, 2_450],
+        [algebra_ark_vesta_fq_neg: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fq_neg" }, 1_152],
+        [algebra_ark_vesta_fq_one: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fq_one" }, 23],
+        [algebra_ark_vesta_fq_serialize: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fq_serialize" }, 6_620],
+        [algebra_ark_vesta_fq_square: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fq_square" }, 1_174],
+        [algebra_ark_vesta_fq_sub: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fq_sub" }, 1_191],
+        [algebra_ark_vesta_fq_zero: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fq_zero" }, 23],
+        [algebra_ark_vesta_fr_add: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fr_add" }, 787],
+        [algebra_ark_vesta_fr_deser: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fr_deser" }, 3_066],
+        [algebra_ark_vesta_fr_div: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fr_div" }, 72_438],
+        [algebra_ark_vesta_fr_eq: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fr_eq" }, 796],
+        [algebra_ark_vesta_fr_from_u64: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fr_from_u64" }, 1_563],
+        [algebra_ark_vesta_fr_inv: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fr_inv" }, 69_543],
+        [algebra_ark_vesta_fr_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fr_mul" }, 1_402],
+        [algebra_ark_vesta_fr_neg: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fr_neg" }, 903],
+        [algebra_ark_vesta_fr_one: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fr_one" }, 23],
+        [algebra_ark_vesta_fr_serialize: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fr_serialize" }, 4_876],
+        [algebra_ark_vesta_fr_square: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fr_square" }, 1_050],
+        [algebra_ark_vesta_fr_sub: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fr_sub" }, 802],
+        [algebra_ark_vesta_fr_zero: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_fr_zero" }, 23],
+        [algebra_ark_vesta_g1_affine_deser_comp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_g1_affine_deser_comp" }, 396_476],
+        [algebra_ark_vesta_g1_affine_deser_uncomp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_g1_affine_deser_uncomp" }, 6_003],
+        [algebra_ark_vesta_g1_affine_serialize_comp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_g1_affine_serialize_comp" }, 1_910],
+        [algebra_ark_vesta_g1_affine_serialize_uncomp: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_g1_affine_serialize_uncomp" }, 7_881],
+        [algebra_ark_vesta_g1_proj_add: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_g1_proj_add" }, 20_954],
+        [algebra_ark_vesta_g1_proj_double: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_g1_proj_double" }, 10_425],
+        [algebra_ark_vesta_g1_proj_eq: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_g1_proj_eq" }, 12_534],
+        [algebra_ark_vesta_g1_proj_generator: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_g1_proj_generator" }, 23],
+        [algebra_ark_vesta_g1_proj_infinity: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_g1_proj_infinity" }, 23],
+        [algebra_ark_vesta_g1_proj_neg: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_g1_proj_neg" }, 23],
+        [algebra_ark_vesta_g1_proj_scalar_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_g1_proj_scalar_mul" }, 4_818_208],
+        [algebra_ark_vesta_g1_proj_sub: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_g1_proj_sub" }, 17_606],
+        [algebra_ark_vesta_g1_proj_to_affine: InternalGas, { RELEASE_V1_14.. => "algebra.ark_vesta_g1_proj_to_affine" }, 1_109],
+        // Vesta algebra gas parameters end.
 
         // BLS12-381 algebra gas parameters begin.
         // Generated at time 1680606720.0709136 by `scripts/algebra-gas/update_algebra_gas_params.py` with gas_per_ns=204.6.
@@ -160,6 +385,36 @@ crate::gas_schedule::macros::define_gas_parameters!(
         [algebra_ark_h2c_bls12381g2_xmd_sha256_sswu_per_msg_byte: InternalGasPerByte, { 8.. => "algebra.ark_h2c_bls12381g2_xmd_sha256_sswu_per_msg_byte" }, 176],
         // BLS12-381 algebra gas parameters end.
 
+        // BLS12-381 base field algebra gas parameters begin.
+        // Generated at time 1792139279.9067204 by `scripts/algebra-gas/update_bls12381_base_field_gas_params.py` with gas_per_ns=21.815554376600325.
+        [algebra_ark_bls12_381_fq2_add: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq2_add" }, 1_579],
+        [algebra_ark_bls12_381_fq2_deser: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq2_deser" }, 6_863],
+        [algebra_ark_bls12_381_fq2_div: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq2_div" }, 268_934],
+        [algebra_ark_bls12_381_fq2_eq: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq2_eq" }, 790],
+        [algebra_ark_bls12_381_fq2_from_u64: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq2_from_u64" }, 2_608],
+        [algebra_ark_bls12_381_fq2_inv: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq2_inv" }, 280_494],
+        [algebra_ark_bls12_381_fq2_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq2_mul" }, 7_877],
+        [algebra_ark_bls12_381_fq2_neg: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq2_neg" }, 754],
+        [algebra_ark_bls12_381_fq2_one: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq2_one" }, 21],
+        [algebra_ark_bls12_381_fq2_serialize: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq2_serialize" }, 6_897],
+        [algebra_ark_bls12_381_fq2_square: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq2_square" }, 7_720],
+        [algebra_ark_bls12_381_fq2_sub: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq2_sub" }, 1_309],
+        [algebra_ark_bls12_381_fq2_zero: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq2_zero" }, 21],
+        [algebra_ark_bls12_381_fq_add: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq_add" }, 1_282],
+        [algebra_ark_bls12_381_fq_deser: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq_deser" }, 4_090],
+        [algebra_ark_bls12_381_fq_div: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq_div" }, 254_597],
+        [algebra_ark_bls12_381_fq_eq: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq_eq" }, 749],
+        [algebra_ark_bls12_381_fq_from_u64: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq_from_u64" }, 2_674],
+        [algebra_ark_bls12_381_fq_inv: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq_inv" }, 284_832],
+        [algebra_ark_bls12_381_fq_mul: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq_mul" }, 4_092],
+        [algebra_ark_bls12_381_fq_neg: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq_neg" }, 1_087],
+        [algebra_ark_bls12_381_fq_one: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq_one" }, 21],
+        [algebra_ark_bls12_381_fq_serialize: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq_serialize" }, 8_899],
+        [algebra_ark_bls12_381_fq_square: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq_square" }, 1_092],
+        [algebra_ark_bls12_381_fq_sub: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq_sub" }, 1_393],
+        [algebra_ark_bls12_381_fq_zero: InternalGas, { RELEASE_V1_14.. => "algebra.ark_bls12_381_fq_zero" }, 21],
+        // BLS12-381 base field algebra gas parameters end.
+
         [bls12381_base: InternalGas, "bls12381.base", 551],
 
         [bls12381_per_pubkey_deserialize: InternalGasPerArg, "bls12381.per_pubkey_deserialize", 400684],
@@ -236,6 +491,28 @@ crate::gas_schedule::macros::define_gas_parameters!(
         [bulletproofs_per_byte_rangeproof_deserialize: InternalGasPerByte, { 11.. => "bulletproofs.per_byte_rangeproof_deserialize" }, 121],
         // Bulletproofs gas parameters end.
 
+        // Groth16 over BN254, derived from the BN254 algebra gas parameters.
+        // Base: 3 G1 and 4 G2 compressed deserializations, `e(alpha, beta)` and a 3-pair multi-pairing.
+        [groth16_bn254_verify_base: InternalGas, { RELEASE_V1_14.. => "groth16.bn254_verify.base" }, 162057387], // 3 * 4_318_809 + 4 * 12_445_138 + 38_543_565 + 23_488_646 + 3 * 12_429_399
+        // Per input: one G1 compressed deserialization, scalar multiplication and addition, and an `Fr` deserialization.
+        [groth16_bn254_verify_per_public_input: InternalGasPerArg, { RELEASE_V1_14.. => "groth16.bn254_verify.per_public_input" }, 9204139], // 4_318_809 + 4_862_683 + 19_574 + 3_073
+
+        // KZG opening verification over BN254, derived from the BN254 algebra gas parameters.
+        // 2 G1 and 1 G2 compressed deserializations, 2 G1 scalar multiplications and additions,
+        // 2 `Fr` deserializations and a 2-pair multi-pairing.
+        [kzg_bn254_verify_base: InternalGas, { RELEASE_V1_14.. => "kzg.bn254_verify.base" }, 79200860], // 2 * 4_318_809 + 12_445_138 + 2 * 4_862_683 + 2 * 19_574 + 2 * 3_073 + 23_488_646 + 2 * 12_429_399
+
+        // Modular exponentiation, priced by the EIP-198 formula plus a per-exponent-bit term.
+        // Calibrated with `cargo bench -p aptos-crypto --bench modexp` at gas_per_ns=23.98, the BN254-calibrated value of
+        // `scripts/algebra-gas/update_prime_order_curves_gas_params.py` for the same run, with a 1.3x margin:
+        // - With an 8-byte modulus, a charged iteration takes up to 281ns (even modulus, 1024-byte exponent).
+        // - With a 4096-byte modulus, an EIP-198 unit takes up to 7.0ns (odd modulus, 1-byte exponent).
+        // - The Montgomery setup of an odd modulus costs 82 to 94 exponent bits, covered by the 96 the native adds.
+        // Over the 26 benched moduli of 8 to 4096 bytes and exponents of 1 to 1024 bytes, this charges 1.3x to 28x the measured time.
+        [modexp_base: InternalGas, { RELEASE_V1_14.. => "modexp.base" }, 11028],
+        [modexp_per_exponent_bit: InternalGasPerArg, { RELEASE_V1_14.. => "modexp.per_exponent_bit" }, 8764],
+        [modexp_per_eip198_gas_unit: InternalGasPerArg, { RELEASE_V1_14.. => "modexp.per_eip198_gas_unit" }, 217],
+
         [type_info_type_of_base: InternalGas, "type_info.type_of.base", 1102],
         // TODO(Gas): the on-chain name is wrong...
         [type_info_type_of_per_byte_in_str: InternalGasPerByte, "type_info.type_of.per_abstract_memory_unit", 18],
@@ -262,6 +539,11 @@ crate::gas_schedule::macros::define_gas_parameters!(
         [hash_ripemd160_per_byte: InternalGasPerByte, { 4.. => "hash.ripemd160.per_byte" }, 183], // 50 * 20
         [hash_blake2b_256_base: InternalGas, { 6.. => "hash.blake2b_256.base" }, 6433], // 1750 * 20
         [hash_blake2b_256_per_byte: InternalGasPerByte, { 6.. => "hash.blake2b_256.per_byte" }, 55], // 15 * 20
+        // Keccak-256's cost, plus a BN254 `Fr` deserialization for the reduction
+        [hash_keccak_to_bn254_fr_base: InternalGas, { RELEASE_V1_14.. => "hash.keccak_to_bn254_fr.base" }, 17777], // 14_704 + 3_073
+        [hash_keccak_to_bn254_fr_per_byte: InternalGasPerByte, { RELEASE_V1_14.. => "hash.keccak_to_bn254_fr.per_byte" }, 165],
+        // One 64-byte block at the SHA2-256 per-byte price
+        [hash_sha256_compress_base: InternalGas, { RELEASE_V1_14.. => "hash.sha256_compress.base" }, 11712], // 64 * 183
 
         [util_from_bytes_base: InternalGas, "util.from_bytes.base", 1102],
         [util_from_bytes_per_byte: InternalGasPerByte, "util.from_bytes.per_byte", 18],
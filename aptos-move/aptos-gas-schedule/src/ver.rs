@@ -8,6 +8,19 @@
 ///   - Changing how gas is calculated in any way
 ///
 /// Change log:
+/// - V19
+///   - Added secp256k1 and secp256r1 algebra operations.
+///   - Added Grumpkin algebra operations.
+///   - Added Pallas and Vesta algebra operations.
+///   - Added the Keccak-256 to BN254 scalar hash native.
+///   - Added the Groth16 proof verification native over BN254.
+///   - Added the KZG opening verification native over BN254.
+///   - Added BN254 Fq12 cyclotomic exponentiation.
+///   - Added the BN254 field modulus natives.
+///   - Added the modular exponentiation native.
+///   - Added BLS12-381 Fq and Fq2 algebra operations.
+///   - Added the SHA-256 compression function native.
+///   - Added the BN254 transcript to scalar native.
 /// - V18
 ///   - Separate limits for governance scripts
 ///   - Function info & dispatchable token gas params
@@ -59,11 +72,12 @@
 ///       global operations.
 /// - V1
 ///   - TBA
-pub const LATEST_GAS_FEATURE_VERSION: u64 = 18;
+pub const LATEST_GAS_FEATURE_VERSION: u64 = 19;
 
 #[allow(dead_code)]
 pub mod gas_feature_versions {
     pub const RELEASE_V1_11: u64 = 16;
     pub const RELEASE_V1_12: u64 = 17;
     pub const RELEASE_V1_13: u64 = 18;
+    pub const RELEASE_V1_14: u64 = 19;
 }
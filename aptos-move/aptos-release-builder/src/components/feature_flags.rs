@@ -114,6 +114,22 @@ pub enum FeatureFlag {
     PrimaryAPTFungibleStoreAtUserAddress,
     ObjectNativeDerivedAddress,
     DispatchableFungibleAsset,
+    Secp256k1Secp256r1Structures,
+    GrumpkinStructures,
+    PastaStructures,
+    KeccakToBn254FrNative,
+    Groth16Bn254Native,
+    KzgBn254Native,
+    ModexpNative,
+    Bls12381BaseFieldStructures,
+    Sha256CompressNative,
+    AlgebraDeserializeCheckedNative,
+    Bn254Fq12CyclotomicPowNative,
+    Bn254ModuliNative,
+    Bn254FrBytesNative,
+    Bn254FrFromTranscriptNative,
+    AlgebraIsZeroIsOneNatives,
+    AlgebraPowU64Native,
 }
 
 fn generate_features_blob(writer: &CodeWriter, data: &[u64]) {
@@ -292,6 +308,34 @@ impl From<FeatureFlag> for AptosFeatureFlag {
                 AptosFeatureFlag::OBJECT_NATIVE_DERIVED_ADDRESS
             },
             FeatureFlag::DispatchableFungibleAsset => AptosFeatureFlag::DISPATCHABLE_FUNGIBLE_ASSET,
+            FeatureFlag::Secp256k1Secp256r1Structures => {
+                AptosFeatureFlag::SECP256K1_SECP256R1_STRUCTURES
+            },
+            FeatureFlag::GrumpkinStructures => AptosFeatureFlag::GRUMPKIN_STRUCTURES,
+            FeatureFlag::PastaStructures => AptosFeatureFlag::PASTA_STRUCTURES,
+            FeatureFlag::KeccakToBn254FrNative => AptosFeatureFlag::KECCAK_TO_BN254_FR_NATIVE,
+            FeatureFlag::Groth16Bn254Native => AptosFeatureFlag::GROTH16_BN254_NATIVE,
+            FeatureFlag::KzgBn254Native => AptosFeatureFlag::KZG_BN254_NATIVE,
+            FeatureFlag::ModexpNative => AptosFeatureFlag::MODEXP_NATIVE,
+            FeatureFlag::Bls12381BaseFieldStructures => {
+                AptosFeatureFlag::BLS12_381_BASE_FIELD_STRUCTURES
+            },
+            FeatureFlag::Sha256CompressNative => AptosFeatureFlag::SHA256_COMPRESS_NATIVE,
+            FeatureFlag::AlgebraDeserializeCheckedNative => {
+                AptosFeatureFlag::ALGEBRA_DESERIALIZE_CHECKED_NATIVE
+            },
+            FeatureFlag::Bn254Fq12CyclotomicPowNative => {
+                AptosFeatureFlag::BN254_FQ12_CYCLOTOMIC_POW_NATIVE
+            },
+            FeatureFlag::Bn254ModuliNative => AptosFeatureFlag::BN254_MODULI_NATIVE,
+            FeatureFlag::Bn254FrBytesNative => AptosFeatureFlag::BN254_FR_BYTES_NATIVE,
+            FeatureFlag::Bn254FrFromTranscriptNative => {
+                AptosFeatureFlag::BN254_FR_FROM_TRANSCRIPT_NATIVE
+            },
+            FeatureFlag::AlgebraIsZeroIsOneNatives => {
+                AptosFeatureFlag::ALGEBRA_IS_ZERO_IS_ONE_NATIVES
+            },
+            FeatureFlag::AlgebraPowU64Native => AptosFeatureFlag::ALGEBRA_POW_U64_NATIVE,
         }
     }
 }
@@ -399,6 +443,34 @@ impl From<AptosFeatureFlag> for FeatureFlag {
                 FeatureFlag::ObjectNativeDerivedAddress
             },
             AptosFeatureFlag::DISPATCHABLE_FUNGIBLE_ASSET => FeatureFlag::DispatchableFungibleAsset,
+            AptosFeatureFlag::SECP256K1_SECP256R1_STRUCTURES => {
+                FeatureFlag::Secp256k1Secp256r1Structures
+            },
+            AptosFeatureFlag::GRUMPKIN_STRUCTURES => FeatureFlag::GrumpkinStructures,
+            AptosFeatureFlag::PASTA_STRUCTURES => FeatureFlag::PastaStructures,
+            AptosFeatureFlag::KECCAK_TO_BN254_FR_NATIVE => FeatureFlag::KeccakToBn254FrNative,
+            AptosFeatureFlag::GROTH16_BN254_NATIVE => FeatureFlag::Groth16Bn254Native,
+            AptosFeatureFlag::KZG_BN254_NATIVE => FeatureFlag::KzgBn254Native,
+            AptosFeatureFlag::MODEXP_NATIVE => FeatureFlag::ModexpNative,
+            AptosFeatureFlag::BLS12_381_BASE_FIELD_STRUCTURES => {
+                FeatureFlag::Bls12381BaseFieldStructures
+            },
+            AptosFeatureFlag::SHA256_COMPRESS_NATIVE => FeatureFlag::Sha256CompressNative,
+            AptosFeatureFlag::ALGEBRA_DESERIALIZE_CHECKED_NATIVE => {
+                FeatureFlag::AlgebraDeserializeCheckedNative
+            },
+            AptosFeatureFlag::BN254_FQ12_CYCLOTOMIC_POW_NATIVE => {
+                FeatureFlag::Bn254Fq12CyclotomicPowNative
+            },
+            AptosFeatureFlag::BN254_MODULI_NATIVE => FeatureFlag::Bn254ModuliNative,
+            AptosFeatureFlag::BN254_FR_BYTES_NATIVE => FeatureFlag::Bn254FrBytesNative,
+            AptosFeatureFlag::BN254_FR_FROM_TRANSCRIPT_NATIVE => {
+                FeatureFlag::Bn254FrFromTranscriptNative
+            },
+            AptosFeatureFlag::ALGEBRA_IS_ZERO_IS_ONE_NATIVES => {
+                FeatureFlag::AlgebraIsZeroIsOneNatives
+            },
+            AptosFeatureFlag::ALGEBRA_POW_U64_NATIVE => FeatureFlag::AlgebraPowU64Native,
         }
     }
 }
@@ -0,0 +1,17 @@
+module 0xbeef::test {
+    use aptos_std::bn254_algebra::Fr;
+    use aptos_std::crypto_algebra::{eq, from_u64, pow_u64};
+
+    /// Calls `pow_u64` `count` times with the same type argument, so every call after the first
+    /// resolves `Fr` from the structure cache.
+    public entry fun pow_loop(count: u64) {
+        let x = from_u64<Fr>(3);
+        let y = x;
+        let i = 0;
+        while (i < count) {
+            y = pow_u64(&x, 2);
+            i = i + 1;
+        };
+        assert!(eq(&y, &from_u64<Fr>(9)), 1);
+    }
+}
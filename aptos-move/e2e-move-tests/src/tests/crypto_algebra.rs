@@ -0,0 +1,33 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{assert_success, tests::common, MoveHarness};
+use aptos_types::{account_address::AccountAddress, on_chain_config::FeatureFlag};
+use std::time::Instant;
+
+/// Run with `cargo test <test_name> -- --nocapture` to see output.
+
+#[test]
+fn pow_u64_with_the_same_type_arg() {
+    let mut h = MoveHarness::new_with_features(vec![FeatureFlag::ALGEBRA_POW_U64_NATIVE], vec![]);
+
+    // Load the code
+    let acc = h.new_account_at(AccountAddress::from_hex_literal("0xbeef").unwrap());
+    assert_success!(h.publish_package_cache_building(
+        &acc,
+        &common::test_dir_path("crypto_algebra.data/pow_loop"),
+    ));
+
+    let t0 = Instant::now();
+    let result = h.run_entry_function(
+        &acc,
+        str::parse("0xbeef::test::pow_loop").unwrap(),
+        vec![],
+        vec![bcs::to_bytes(&10_000u64).unwrap()],
+    );
+    let t1 = Instant::now();
+
+    println!("{:?}", t1 - t0);
+
+    assert_success!(result);
+}
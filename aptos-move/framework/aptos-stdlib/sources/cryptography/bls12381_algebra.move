@@ -3,7 +3,7 @@
 /// See https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-pairing-friendly-curves-11#name-bls-curves-for-the-128-bit-
 /// for the full specification of BLS12-381 curves.
 ///
-/// Currently-supported BLS12-381 structures include `Fq12`, `Fr`, `G1`, `G2` and `Gt`,
+/// Currently-supported BLS12-381 structures include `Fq`, `Fq2`, `Fq12`, `Fr`, `G1`, `G2` and `Gt`,
 /// along with their widely-used serialization formats,
 /// the pairing between `G1`, `G2` and `Gt`,
 /// and the hash-to-curve operations for `G1` and `G2` defined in https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve-16.
@@ -12,24 +12,6 @@
 /// as they help define some of the currently supported structures.
 /// Their implementation may also be added in the future.
 ///
-/// `Fq`: the finite field $F_q$ used in BLS12-381 curves with a prime order $q$ equal to
-/// 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab.
-///
-/// `FormatFqLsb`: a serialization format for `Fq` elements,
-/// where an element is represented by a byte array `b[]` of size 48 with the least significant byte (LSB) coming first.
-///
-/// `FormatFqMsb`: a serialization format for `Fq` elements,
-/// where an element is represented by a byte array `b[]` of size 48 with the most significant byte (MSB) coming first.
-///
-/// `Fq2`: the finite field $F_{q^2}$ used in BLS12-381 curves,
-/// which is an extension field of `Fq`, constructed as $F_{q^2}=F_q[u]/(u^2+1)$.
-///
-/// `FormatFq2LscLsb`: a serialization format for `Fq2` elements,
-/// where an element in the form $(c_0+c_1\cdot u)$ is represented by a byte array `b[]` of size 96,
-/// which is a concatenation of its coefficients serialized, with the least significant coefficient (LSC) coming first:
-/// - `b[0..48]` is $c_0$ serialized using `FormatFqLsb`.
-/// - `b[48..96]` is $c_1$ serialized using `FormatFqLsb`.
-///
 /// `FormatFq2MscMsb`: a serialization format for `Fq2` elements,
 /// where an element in the form $(c_0+c_1\cdot u)$ is represented by a byte array `b[]` of size 96,
 /// which is a concatenation of its coefficients serialized, with the most significant coefficient (MSC) coming first:
@@ -58,6 +40,37 @@ module aptos_std::bls12381_algebra {
     // Marker types + serialization formats begin.
     //
 
+    /// The finite field $F_q$ used in BLS12-381 curves with a prime order $q$ equal to
+    /// 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab.
+    /// The coordinates of `G1` elements are `Fq` elements.
+    struct Fq {}
+
+    /// A serialization format for `Fq` elements,
+    /// where an element is represented by a byte array `b[]` of size 48 with the least significant byte (LSB) coming first.
+    ///
+    /// NOTE: other implementation(s) using this format: ark-bls12-381-0.4.0.
+    struct FormatFqLsb {}
+
+    /// A serialization format for `Fq` elements,
+    /// where an element is represented by a byte array `b[]` of size 48 with the most significant byte (MSB) coming first.
+    ///
+    /// NOTE: other implementation(s) using this format: ark-bls12-381-0.4.0, blst-0.3.7.
+    struct FormatFqMsb {}
+
+    /// The finite field $F_{q^2}$ used in BLS12-381 curves,
+    /// which is an extension field of `Fq`, constructed as $F_{q^2}=F_q[u]/(u^2+1)$.
+    /// The coordinates of `G2` elements are `Fq2` elements.
+    struct Fq2 {}
+
+    /// A serialization format for `Fq2` elements,
+    /// where an element in the form $(c_0+c_1\cdot u)$ is represented by a byte array `b[]` of size 96,
+    /// which is a concatenation of its coefficients serialized, with the least significant coefficient (LSC) coming first:
+    /// - `b[0..48]` is $c_0$ serialized using `FormatFqLsb`.
+    /// - `b[48..96]` is $c_1$ serialized using `FormatFqLsb`.
+    ///
+    /// NOTE: other implementation(s) using this format: ark-bls12-381-0.4.0.
+    struct FormatFq2LscLsb {}
+
     /// The finite field $F_{q^12}$ used in BLS12-381 curves,
     /// which is an extension field of `Fq6` (defined in the module documentation), constructed as $F_{q^12}=F_{q^6}[w]/(w^2-v)$.
     struct Fq12 {}
@@ -700,6 +713,137 @@ module aptos_std::bls12381_algebra {
         assert!(eq(&mul(&val_x, &val_x), &sqr(&val_x)), 1);
     }
 
+    #[test_only]
+    const Q_SERIALIZED: vector<u8> = x"abaafffffffffeb9ffff53b1feffab1e24f6b0f6a0d23067bf1285f3844b7764d7ac4b43b6a71b4b9ae67f39ea11011a";
+    #[test_only]
+    const FQ_VAL_0_SERIALIZED_LSB: vector<u8> = x"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FQ_VAL_1_SERIALIZED_LSB: vector<u8> = x"010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FQ_VAL_7_SERIALIZED_LSB: vector<u8> = x"070000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FQ_VAL_7_SERIALIZED_MSB: vector<u8> = x"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000007";
+    #[test_only]
+    const FQ_VAL_7_NEG_SERIALIZED_LSB: vector<u8> = x"a4aafffffffffeb9ffff53b1feffab1e24f6b0f6a0d23067bf1285f3844b7764d7ac4b43b6a71b4b9ae67f39ea11011a";
+
+    #[test(fx = @std)]
+    fun test_fq(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // Constants.
+        assert!(Q_SERIALIZED == order<Fq>(), 1);
+
+        // Serialization/deserialization.
+        let val_0 = zero<Fq>();
+        let val_1 = one<Fq>();
+        assert!(FQ_VAL_0_SERIALIZED_LSB == serialize<Fq, FormatFqLsb>(&val_0), 1);
+        assert!(FQ_VAL_1_SERIALIZED_LSB == serialize<Fq, FormatFqLsb>(&val_1), 1);
+        let val_7 = from_u64<Fq>(7);
+        let val_7_2nd = std::option::extract(&mut deserialize<Fq, FormatFqLsb>(&FQ_VAL_7_SERIALIZED_LSB));
+        let val_7_3rd = std::option::extract(&mut deserialize<Fq, FormatFqMsb>(&FQ_VAL_7_SERIALIZED_MSB));
+        assert!(eq(&val_7, &val_7_2nd), 1);
+        assert!(eq(&val_7, &val_7_3rd), 1);
+        assert!(FQ_VAL_7_SERIALIZED_LSB == serialize<Fq, FormatFqLsb>(&val_7), 1);
+        assert!(FQ_VAL_7_SERIALIZED_MSB == serialize<Fq, FormatFqMsb>(&val_7), 1);
+
+        // Deserialization should fail if given a byte array of right size but the value is not a member.
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqLsb>(&x"abaafffffffffeb9ffff53b1feffab1e24f6b0f6a0d23067bf1285f3844b7764d7ac4b43b6a71b4b9ae67f39ea11011a")), 1);
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqMsb>(&x"1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab")), 1);
+
+        // Deserialization should fail if given a byte array of wrong size.
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqLsb>(&x"abaafffffffffeb9ffff53b1feffab1e24f6b0f6a0d23067bf1285f3844b7764d7ac4b43b6a71b4b9ae67f39ea11011a00")), 1);
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqMsb>(&x"001a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab")), 1);
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqLsb>(&x"ffff")), 1);
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqMsb>(&x"ffff")), 1);
+
+        // Negation.
+        let val_minus_7 = neg(&val_7);
+        assert!(FQ_VAL_7_NEG_SERIALIZED_LSB == serialize<Fq, FormatFqLsb>(&val_minus_7), 1);
+
+        // Addition.
+        let val_9 = from_u64<Fq>(9);
+        let val_2 = from_u64<Fq>(2);
+        assert!(eq(&val_2, &add(&val_minus_7, &val_9)), 1);
+
+        // Subtraction.
+        assert!(eq(&val_9, &sub(&val_2, &val_minus_7)), 1);
+
+        // Multiplication.
+        let val_63 = from_u64<Fq>(63);
+        assert!(eq(&val_63, &mul(&val_7, &val_9)), 1);
+
+        // division.
+        assert!(eq(&val_7, &std::option::extract(&mut div(&val_63, &val_9))), 1);
+        assert!(std::option::is_none(&div(&val_63, &val_0)), 1);
+
+        // Inversion.
+        assert!(eq(&val_1, &mul(&val_7, &std::option::extract(&mut inv(&val_7)))), 1);
+        assert!(std::option::is_none(&inv(&val_0)), 1);
+
+        // Squaring.
+        let val_x = rand_insecure<Fq>();
+        assert!(eq(&mul(&val_x, &val_x), &sqr(&val_x)), 1);
+    }
+
+    #[test_only]
+    const Q2_SERIALIZED: vector<u8> = x"398e711c0000aa26ab2e38761d6bed7cfd3c116283332c1643b7713eed91bf6649a09170a8852e297b5c18869c61681d01ef7809334931f56ecadd16fd2ca650d08b9e34499ee566b446700ee590dce2e9252fa2ea78d24bc75fc3b8a437a402";
+    #[test_only]
+    const FQ2_VAL_7_SERIALIZED: vector<u8> = x"070000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FQ2_VAL_U_SERIALIZED: vector<u8> = x"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FQ2_VAL_1_PLUS_2U_SERIALIZED: vector<u8> = x"010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FQ2_VAL_3_PLUS_4U_SERIALIZED: vector<u8> = x"030000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FQ2_VAL_MINUS_5_PLUS_10U_SERIALIZED: vector<u8> = x"a6aafffffffffeb9ffff53b1feffab1e24f6b0f6a0d23067bf1285f3844b7764d7ac4b43b6a71b4b9ae67f39ea11011a0a0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+
+    #[test(fx = @std)]
+    fun test_fq2(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // Constants.
+        assert!(Q2_SERIALIZED == order<Fq2>(), 1);
+
+        // Serialization/deserialization: `c_0` comes first, `c_1` second, each as an `Fq` LSB encoding.
+        let val_7 = from_u64<Fq2>(7);
+        assert!(FQ2_VAL_7_SERIALIZED == serialize<Fq2, FormatFq2LscLsb>(&val_7), 1);
+        let val_u = std::option::extract(&mut deserialize<Fq2, FormatFq2LscLsb>(&FQ2_VAL_U_SERIALIZED));
+        assert!(FQ2_VAL_U_SERIALIZED == serialize<Fq2, FormatFq2LscLsb>(&val_u), 1);
+
+        // Deserialization should fail if a coefficient is not an `Fq` member.
+        assert!(std::option::is_none(&deserialize<Fq2, FormatFq2LscLsb>(&x"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000abaafffffffffeb9ffff53b1feffab1e24f6b0f6a0d23067bf1285f3844b7764d7ac4b43b6a71b4b9ae67f39ea11011a")), 1);
+
+        // Deserialization should fail if given a byte array of wrong size.
+        assert!(std::option::is_none(&deserialize<Fq2, FormatFq2LscLsb>(&FQ_VAL_7_SERIALIZED_LSB)), 1);
+        assert!(std::option::is_none(&deserialize<Fq2, FormatFq2LscLsb>(&x"ffff")), 1);
+
+        // `u^2 = -1`.
+        let val_1 = one<Fq2>();
+        assert!(eq(&neg(&val_1), &sqr(&val_u)), 1);
+
+        // `(1 + 2u) * (3 + 4u) = -5 + 10u`.
+        let val_a = std::option::extract(&mut deserialize<Fq2, FormatFq2LscLsb>(&FQ2_VAL_1_PLUS_2U_SERIALIZED));
+        let val_b = std::option::extract(&mut deserialize<Fq2, FormatFq2LscLsb>(&FQ2_VAL_3_PLUS_4U_SERIALIZED));
+        let val_ab = mul(&val_a, &val_b);
+        assert!(FQ2_VAL_MINUS_5_PLUS_10U_SERIALIZED == serialize<Fq2, FormatFq2LscLsb>(&val_ab), 1);
+
+        // Addition and subtraction.
+        assert!(eq(&val_b, &add(&val_a, &add(&val_1, &add(&val_1, &add(&val_u, &val_u))))), 1);
+        assert!(eq(&val_a, &sub(&val_b, &add(&val_1, &add(&val_1, &add(&val_u, &val_u))))), 1);
+
+        // Division and inversion.
+        let val_0 = zero<Fq2>();
+        assert!(eq(&val_a, &std::option::extract(&mut div(&val_ab, &val_b))), 1);
+        assert!(std::option::is_none(&div(&val_ab, &val_0)), 1);
+        assert!(eq(&val_1, &mul(&val_b, &std::option::extract(&mut inv(&val_b)))), 1);
+        assert!(std::option::is_none(&inv(&val_0)), 1);
+
+        // Squaring.
+        let val_x = rand_insecure<Fq2>();
+        assert!(eq(&mul(&val_x, &val_x), &sqr(&val_x)), 1);
+    }
+
     #[test(fx = @std)]
     fun test_pairing(fx: signer) {
         enable_cryptography_algebra_natives(&fx);
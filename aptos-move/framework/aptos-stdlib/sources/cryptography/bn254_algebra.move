@@ -62,6 +62,12 @@
 /// under the elliptic curve point addition.
 /// It contains the prime-order subgroup $G_2$ used in pairing.
 module std::bn254_algebra {
+    use std::features;
+    use std::option::{Self, Option};
+
+    /// A newly-added native function is not yet enabled.
+    const E_NATIVE_FUN_NOT_AVAILABLE: u64 = 1;
+
     //
     // Marker types + serialization formats begin.
     //
@@ -231,6 +237,111 @@ module std::bn254_algebra {
     /// NOTE: other implementation(s) using this format: ark-bn254-0.4.0.
     struct FormatGt {}
 
+    // Field moduli.
+
+    /// Return the modulus $r$ of `Fr`, which is also the order of `G1`, `G2` and `Gt`.
+    /// Move code that reduces values modulo $r$ itself should use this instead of a hardcoded literal.
+    ///
+    /// Aborts with `std::error::invalid_state(1)` if the native is not enabled.
+    public fun bn254_fr_modulus(): u256 {
+        if(!features::bn254_moduli_enabled()) {
+            abort(std::error::invalid_state(E_NATIVE_FUN_NOT_AVAILABLE))
+        };
+
+        bn254_fr_modulus_internal()
+    }
+
+    /// Return the modulus $q$ of `Fq`.
+    ///
+    /// Aborts with `std::error::invalid_state(1)` if the native is not enabled.
+    public fun bn254_fq_modulus(): u256 {
+        if(!features::bn254_moduli_enabled()) {
+            abort(std::error::invalid_state(E_NATIVE_FUN_NOT_AVAILABLE))
+        };
+
+        bn254_fq_modulus_internal()
+    }
+
+    native fun bn254_fr_modulus_internal(): u256;
+    native fun bn254_fq_modulus_internal(): u256;
+
+    // Byte conversions of `Fr` values.
+
+    /// Encode `x` as an `Fr` element in 32 bytes, least significant byte first.
+    /// The result is the same as serializing the element with `FormatFrLsb`.
+    ///
+    /// Aborts with `std::error::invalid_argument(7)` if `x` is not less than $r$,
+    /// and with `std::error::invalid_state(1)` if the native is not enabled.
+    public fun bn254_fr_to_bytes_le(x: u256): vector<u8> {
+        if(!features::bn254_fr_bytes_enabled()) {
+            abort(std::error::invalid_state(E_NATIVE_FUN_NOT_AVAILABLE))
+        };
+
+        bn254_fr_to_bytes_internal(x, false)
+    }
+
+    /// Encode `x` as an `Fr` element in 32 bytes, most significant byte first.
+    /// The result is the same as serializing the element with `FormatFrMsb`.
+    ///
+    /// Aborts with `std::error::invalid_argument(7)` if `x` is not less than $r$,
+    /// and with `std::error::invalid_state(1)` if the native is not enabled.
+    public fun bn254_fr_to_bytes_be(x: u256): vector<u8> {
+        if(!features::bn254_fr_bytes_enabled()) {
+            abort(std::error::invalid_state(E_NATIVE_FUN_NOT_AVAILABLE))
+        };
+
+        bn254_fr_to_bytes_internal(x, true)
+    }
+
+    /// Decode an `Fr` element from 32 bytes, least significant byte first, as accepted by `FormatFrLsb`.
+    /// Return none if `bytes` is not 32 bytes long or encodes a value not less than $r$.
+    ///
+    /// Aborts with `std::error::invalid_state(1)` if the native is not enabled.
+    public fun bn254_fr_from_bytes_le(bytes: vector<u8>): Option<u256> {
+        if(!features::bn254_fr_bytes_enabled()) {
+            abort(std::error::invalid_state(E_NATIVE_FUN_NOT_AVAILABLE))
+        };
+
+        let (succeeded, x) = bn254_fr_from_bytes_internal(bytes, false);
+        if (succeeded) { option::some(x) } else { option::none() }
+    }
+
+    /// Decode an `Fr` element from 32 bytes, most significant byte first, as accepted by `FormatFrMsb`.
+    /// Return none if `bytes` is not 32 bytes long or encodes a value not less than $r$.
+    ///
+    /// Aborts with `std::error::invalid_state(1)` if the native is not enabled.
+    public fun bn254_fr_from_bytes_be(bytes: vector<u8>): Option<u256> {
+        if(!features::bn254_fr_bytes_enabled()) {
+            abort(std::error::invalid_state(E_NATIVE_FUN_NOT_AVAILABLE))
+        };
+
+        let (succeeded, x) = bn254_fr_from_bytes_internal(bytes, true);
+        if (succeeded) { option::some(x) } else { option::none() }
+    }
+
+    native fun bn254_fr_to_bytes_internal(x: u256, big_endian: bool): vector<u8>;
+    native fun bn254_fr_from_bytes_internal(bytes: vector<u8>, big_endian: bool): (bool, u256);
+
+    // Hash to `Fr`.
+
+    /// Derive an `Fr` element from a Fiat-Shamir transcript, e.g., a challenge in a verifier.
+    ///
+    /// The result is `SHA2-512("APTOS_BN254_FR_FROM_TRANSCRIPT" || bytes) mod r`, with the digest read as a
+    /// big-endian integer. It is always less than $r$, and as the digest is 512 bits wide, its distribution is
+    /// statistically close to uniform. Verifiers that need to match another hash-to-field construction,
+    /// e.g., `uint256(keccak256(bytes)) % r` in Solidity, should use `aptos_std::aptos_hash::keccak_to_bn254_fr`.
+    ///
+    /// Aborts with `std::error::invalid_state(1)` if the native is not enabled.
+    public fun bn254_fr_from_transcript(bytes: vector<u8>): u256 {
+        if(!features::bn254_fr_from_transcript_enabled()) {
+            abort(std::error::invalid_state(E_NATIVE_FUN_NOT_AVAILABLE))
+        };
+
+        bn254_fr_from_transcript_internal(bytes)
+    }
+
+    native fun bn254_fr_from_transcript_internal(bytes: vector<u8>): u256;
+
     // Tests begin.
 
     #[test_only]
@@ -361,6 +472,12 @@ module std::bn254_algebra {
         assert!(eq(&point_at_infinity, &inf_from_comp), 1);
         assert!(eq(&point_at_infinity, &inf_from_uncomp), 1);
 
+        // `zero()` and `one()` are the identity and the canonical generator.
+        assert!(eq(&generator, &add(&point_at_infinity, &generator)), 1);
+        assert!(eq(&generator, &add(&generator, &point_at_infinity)), 1);
+        assert!(eq(&point_at_infinity, &add(&point_at_infinity, &point_at_infinity)), 1);
+        assert!(!eq(&generator, &point_at_infinity), 1);
+
         let point_7g_from_uncomp = std::option::extract(&mut deserialize<G1, FormatG1Uncompr>(&G1_GENERATOR_MUL_BY_7_SERIALIZED_UNCOMP
         ));
         let point_7g_from_comp = std::option::extract(&mut deserialize<G1, FormatG1Compr>(&G1_GENERATOR_MUL_BY_7_SERIALIZED_COMP
@@ -415,9 +532,11 @@ module std::bn254_algebra {
         let point_2g = scalar_mul(&generator, &scalar_2);
         let point_double_g = double(&generator);
         assert!(eq(&point_2g, &point_double_g), 1);
+        assert!(eq(&add(&point_7g_calc, &point_7g_calc), &double(&point_7g_calc)), 1);
 
         // Negation.
         let point_minus_7g_calc = neg(&point_7g_calc);
+        assert!(eq(&point_7g_calc, &neg(&point_minus_7g_calc)), 1);
         assert!(G1_GENERATOR_MUL_BY_7_NEG_SERIALIZED_COMP == serialize<G1, FormatG1Compr>(&point_minus_7g_calc), 1);
         assert!(G1_GENERATOR_MUL_BY_7_NEG_SERIALIZED_UNCOMP == serialize<G1, FormatG1Uncompr>(&point_minus_7g_calc), 1);
 
@@ -473,6 +592,12 @@ module std::bn254_algebra {
         let inf_from_comp = std::option::extract(&mut deserialize<G2, FormatG2Compr>(&G2_INF_SERIALIZED_COMP));
         assert!(eq(&point_at_infinity, &inf_from_comp), 1);
         assert!(eq(&point_at_infinity, &inf_from_uncomp), 1);
+
+        // `zero()` and `one()` are the identity and the canonical generator.
+        assert!(eq(&generator, &add(&point_at_infinity, &generator)), 1);
+        assert!(eq(&generator, &add(&generator, &point_at_infinity)), 1);
+        assert!(eq(&point_at_infinity, &add(&point_at_infinity, &point_at_infinity)), 1);
+        assert!(!eq(&generator, &point_at_infinity), 1);
         let point_7g_from_uncomp = std::option::extract(&mut deserialize<G2, FormatG2Uncompr>(&G2_GENERATOR_MUL_BY_7_SERIALIZED_UNCOMP
         ));
         let point_7g_from_comp = std::option::extract(&mut deserialize<G2, FormatG2Compr>(&G2_GENERATOR_MUL_BY_7_SERIALIZED_COMP
@@ -527,9 +652,11 @@ module std::bn254_algebra {
         let point_2g = scalar_mul(&generator, &scalar_2);
         let point_double_g = double(&generator);
         assert!(eq(&point_2g, &point_double_g), 1);
+        assert!(eq(&add(&point_7g_calc, &point_7g_calc), &double(&point_7g_calc)), 1);
 
         // Negation.
         let point_minus_7g_calc = neg(&point_7g_calc);
+        assert!(eq(&point_7g_calc, &neg(&point_minus_7g_calc)), 1);
         assert!(G2_GENERATOR_MUL_BY_7_NEG_SERIALIZED_COMP == serialize<G2, FormatG2Compr>(&point_minus_7g_calc), 1);
         assert!(G2_GENERATOR_MUL_BY_7_NEG_SERIALIZED_UNCOMP == serialize<G2, FormatG2Uncompr>(&point_minus_7g_calc), 1);
 
@@ -605,8 +732,251 @@ module std::bn254_algebra {
         assert!(eq(&one<Fq12>(), &upcast<Gt, Fq12>(&identity)), 1);
     }
 
+    #[test(fx = @std)]
+    fun test_moduli(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        assert!(bn254_fr_modulus() == 21888242871839275222246405745257275088548364400416034343698204186575808495617, 1);
+        assert!(std::bcs::to_bytes(&bn254_fr_modulus()) == order<Fr>(), 1);
+        assert!(bn254_fq_modulus() == 21888242871839275222246405745257275088696311157297823662689037894645226208583, 1);
+        assert!(std::bcs::to_bytes(&bn254_fq_modulus()) == order<Fq>(), 1);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x030001, location = Self)]
+    fun test_moduli_should_abort_when_not_enabled(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        std::features::change_feature_flags_for_testing(&fx, vector[], vector[std::features::get_bn254_moduli_feature()]);
+        bn254_fr_modulus();
+    }
+
+    #[test(fx = @std)]
+    fun test_fr_byte_conversions(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // Small and boundary values.
+        assert!(bn254_fr_to_bytes_le(7) == x"0700000000000000000000000000000000000000000000000000000000000000", 1);
+        assert!(bn254_fr_to_bytes_be(7) == x"0000000000000000000000000000000000000000000000000000000000000007", 1);
+        let r_minus_1 = bn254_fr_modulus() - 1;
+        let r_minus_1_le = x"000000f093f5e1439170b97948e833285d588181b64550b829a031e1724e6430";
+        let r_minus_1_be = x"30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000";
+        assert!(bn254_fr_to_bytes_le(r_minus_1) == r_minus_1_le, 1);
+        assert!(bn254_fr_to_bytes_be(r_minus_1) == r_minus_1_be, 1);
+        assert!(bn254_fr_from_bytes_le(r_minus_1_le) == option::some(r_minus_1), 1);
+        assert!(bn254_fr_from_bytes_be(r_minus_1_be) == option::some(r_minus_1), 1);
+
+        // Non-canonical encodings of $r$ and wrong sizes are rejected.
+        assert!(option::is_none(&bn254_fr_from_bytes_le(x"010000f093f5e1439170b97948e833285d588181b64550b829a031e1724e6430")), 1);
+        assert!(option::is_none(&bn254_fr_from_bytes_be(x"30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001")), 1);
+        assert!(option::is_none(&bn254_fr_from_bytes_le(x"07")), 1);
+        assert!(option::is_none(&bn254_fr_from_bytes_be(x"000000000000000000000000000000000000000000000000000000000000000007")), 1);
+
+        // Cross-check against the `Fr` serialization formats.
+        let element = rand_insecure<Fr>();
+        let element_le = serialize<Fr, FormatFrLsb>(&element);
+        let element_be = serialize<Fr, FormatFrMsb>(&element);
+        let x = option::extract(&mut bn254_fr_from_bytes_le(element_le));
+        assert!(bn254_fr_from_bytes_be(element_be) == option::some(x), 1);
+        assert!(bn254_fr_to_bytes_le(x) == element_le, 1);
+        assert!(bn254_fr_to_bytes_be(x) == element_be, 1);
+        assert!(std::bcs::to_bytes(&x) == element_le, 1);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010007, location = Self)]
+    fun test_fr_to_bytes_non_canonical(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        bn254_fr_to_bytes_le(bn254_fr_modulus());
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x030001, location = Self)]
+    fun test_fr_from_bytes_should_abort_when_not_enabled(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        std::features::change_feature_flags_for_testing(&fx, vector[], vector[std::features::get_bn254_fr_bytes_feature()]);
+        bn254_fr_from_bytes_le(x"0700000000000000000000000000000000000000000000000000000000000000");
+    }
+
+    #[test(fx = @std)]
+    fun test_fr_from_transcript(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // From `int.from_bytes(hashlib.sha512(b"APTOS_BN254_FR_FROM_TRANSCRIPT" + input).digest(), "big") % r` in Python.
+        assert!(bn254_fr_from_transcript(b"") == 20094604910592844386707738747502387991528235728969041233970838062879333232675, 1);
+        assert!(bn254_fr_from_transcript(b"testing") == 17523870165651081589754068578190465032995308358293186116679393613588451746687, 1);
+        assert!(bn254_fr_from_transcript(b"abc") == 21663070080806816985984241485492127694808375584418990659862526714466747457126, 1);
+
+        // Deterministic, and always a canonical element.
+        let r = bn254_fr_modulus();
+        let i = 0;
+        while (i < 16) {
+            let transcript = serialize<Fr, FormatFrLsb>(&rand_insecure<Fr>());
+            let challenge = bn254_fr_from_transcript(transcript);
+            assert!(challenge < r, 1);
+            assert!(challenge == bn254_fr_from_transcript(transcript), 1);
+            i = i + 1;
+        };
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x030001, location = Self)]
+    fun test_fr_from_transcript_should_abort_when_not_enabled(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        std::features::change_feature_flags_for_testing(&fx, vector[], vector[std::features::get_bn254_fr_from_transcript_feature()]);
+        bn254_fr_from_transcript(b"testing");
+    }
+
+    #[test(fx = @std)]
+    fun test_is_zero_and_is_one(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // Identities.
+        assert!(is_zero(&zero<Fr>()), 1);
+        assert!(is_zero(&zero<Fq>()), 1);
+        assert!(is_zero(&zero<Fq12>()), 1);
+        assert!(is_zero(&zero<G1>()), 1);
+        assert!(is_zero(&zero<G2>()), 1);
+        assert!(is_zero(&zero<Gt>()), 1);
+        assert!(is_one(&one<Fr>()), 1);
+        assert!(is_one(&one<Fq>()), 1);
+        assert!(is_one(&one<Fq12>()), 1);
+
+        // Non-identities.
+        assert!(!is_zero(&one<Fr>()), 1);
+        assert!(!is_zero(&from_u64<Fq>(7)), 1);
+        assert!(!is_zero(&one<Fq12>()), 1);
+        assert!(!is_zero(&one<G1>()), 1);
+        assert!(!is_zero(&one<G2>()), 1);
+        assert!(!is_zero(&one<Gt>()), 1);
+        assert!(!is_one(&zero<Fr>()), 1);
+        assert!(!is_one(&from_u64<Fq>(7)), 1);
+        assert!(!is_one(&zero<Fq12>()), 1);
+
+        // Typical acceptance checks.
+        let p = rand_insecure<G1>();
+        assert!(is_zero(&add(&p, &neg(&p))), 1);
+        assert!(!is_zero(&double(&one<G1>())), 1);
+        let e = pairing<G1, G2, Gt>(&one<G1>(), &one<G2>());
+        assert!(!is_zero(&e), 1);
+        assert!(is_zero(&sub(&e, &e)), 1);
+        assert!(is_one(&upcast<Gt, Fq12>(&zero<Gt>())), 1);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x0c0001, location = aptos_std::crypto_algebra)]
+    fun test_is_one_not_implemented_for_groups(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        is_one(&one<G1>());
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x0c0000, location = aptos_std::crypto_algebra)]
+    fun test_is_zero_should_abort_when_not_enabled(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        std::features::change_feature_flags_for_testing(&fx, vector[], vector[std::features::get_algebra_is_zero_is_one_feature()]);
+        is_zero(&zero<Fr>());
+    }
+
+    #[test(fx = @std)]
+    fun test_pow_u64(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        let x = rand_insecure<Fr>();
+        assert!(eq(&one<Fr>(), &pow_u64(&x, 0)), 1);
+        assert!(eq(&x, &pow_u64(&x, 1)), 1);
+        assert!(eq(&mul(&x, &x), &pow_u64(&x, 2)), 1);
+        assert!(eq(&mul(&x, &sqr(&x)), &pow_u64(&x, 3)), 1);
+        assert!(eq(&from_u64<Fr>(1024), &pow_u64(&from_u64<Fr>(2), 10)), 1);
+
+        // x^(a+b) == x^a * x^b, including the largest exponent.
+        let y = rand_insecure<Fq>();
+        assert!(eq(&pow_u64(&y, 18446744073709551615), &mul(&pow_u64(&y, 18446744073709551614), &y)), 1);
+        assert!(eq(&pow_u64(&y, 1000), &mul(&pow_u64(&y, 999), &y)), 1);
+
+        // Consistent with the cyclotomic exponentiation on a pairing output.
+        let z = upcast<Gt, Fq12>(&rand_insecure<Gt>());
+        assert!(eq(&fq12_cyclotomic_pow(&z, 12345), &pow_u64(&z, 12345)), 1);
+        assert!(eq(&mul(&z, &z), &pow_u64(&z, 2)), 1);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x0c0001, location = aptos_std::crypto_algebra)]
+    fun test_pow_u64_not_implemented_for_groups(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        pow_u64(&one<G1>(), 2);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x0c0000, location = aptos_std::crypto_algebra)]
+    fun test_pow_u64_should_abort_when_not_enabled(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        std::features::change_feature_flags_for_testing(&fx, vector[], vector[std::features::get_algebra_pow_u64_feature()]);
+        pow_u64(&one<Fr>(), 2);
+    }
+
+    #[test(fx = @std)]
+    fun test_fq12_cyclotomic_pow(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        let element = rand_insecure<Gt>();
+        let element_fq12 = upcast<Gt, Fq12>(&element);
+        assert!(eq(&one<Fq12>(), &fq12_cyclotomic_pow(&element_fq12, 0)), 1);
+        assert!(eq(&element_fq12, &fq12_cyclotomic_pow(&element_fq12, 1)), 1);
+        assert!(eq(&sqr(&element_fq12), &fq12_cyclotomic_pow(&element_fq12, 2)), 1);
+
+        // Compare against the generic path, which for `Gt` is scalar multiplication.
+        // 4965661367192848881 is the BN254 curve parameter `x`, the exponent of the final exponentiation.
+        let exponent = 4965661367192848881;
+        let expected = upcast<Gt, Fq12>(&scalar_mul(&element, &from_u64<Fr>(exponent)));
+        assert!(eq(&expected, &fq12_cyclotomic_pow(&element_fq12, exponent)), 1);
+        let expected = upcast<Gt, Fq12>(&scalar_mul(&element, &from_u64<Fr>(18446744073709551615)));
+        assert!(eq(&expected, &fq12_cyclotomic_pow(&element_fq12, 18446744073709551615)), 1);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010008, location = aptos_std::crypto_algebra)]
+    fun test_fq12_cyclotomic_pow_should_abort_outside_cyclotomic_subgroup(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        fq12_cyclotomic_pow(&from_u64<Fq12>(2), 3);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010008, location = aptos_std::crypto_algebra)]
+    fun test_fq12_cyclotomic_pow_should_abort_on_zero(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        fq12_cyclotomic_pow(&zero<Fq12>(), 3);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x0c0000, location = aptos_std::crypto_algebra)]
+    fun test_fq12_cyclotomic_pow_should_abort_when_not_enabled(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        std::features::change_feature_flags_for_testing(&fx, vector[], vector[std::features::get_bn254_fq12_cyclotomic_pow_feature()]);
+        fq12_cyclotomic_pow(&one<Fq12>(), 3);
+    }
+
+    #[test(fx = @std)]
+    fun test_gt_and_fq12_in_one_session(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // `Gt` and `Fq12` share an ark type but not their operations: the group law of `Gt`
+        // is multiplication in `Fq12`. Alternate between them within one session, so that each call
+        // resolves its type argument while the other structure is already cached.
+        let element = rand_insecure<Gt>();
+        let element_fq12 = upcast<Gt, Fq12>(&element);
+        let i = 0;
+        while (i < 3) {
+            let gt_sum = add(&element, &element);
+            let fq12_sum = add(&element_fq12, &element_fq12);
+            assert!(eq(&sqr(&element_fq12), &upcast<Gt, Fq12>(&gt_sum)), 1);
+            assert!(eq(&mul(&from_u64<Fq12>(2), &element_fq12), &fq12_sum), 1);
+            assert!(eq(&double(&element), &gt_sum), 1);
+            assert!(!eq(&fq12_sum, &upcast<Gt, Fq12>(&gt_sum)), 1);
+            assert!(is_zero(&sub(&element, &element)), 1);
+            assert!(eq(&zero<Fq12>(), &sub(&element_fq12, &element_fq12)), 1);
+            i = i + 1;
+        };
+    }
+
     #[test_only]
-    use aptos_std::crypto_algebra::{zero, one, from_u64, eq, deserialize, serialize, neg, add, sub, mul, div, inv, rand_insecure, sqr, order, scalar_mul, multi_scalar_mul, double, upcast, enable_cryptography_algebra_natives, pairing, multi_pairing, downcast, Element};
+    use aptos_std::crypto_algebra::{zero, one, from_u64, eq, deserialize, deserialize_checked, point_from_coordinates_for_testing, serialize, neg, add, sub, mul, div, inv, rand_insecure, sqr, fq12_cyclotomic_pow, pow_u64, is_zero, is_one, order, scalar_mul, multi_scalar_mul, double, upcast, enable_cryptography_algebra_natives, pairing, multi_pairing, downcast, Element};
 
     #[test_only]
     const FR_VAL_0_SERIALIZED_LSB: vector<u8> = x"0000000000000000000000000000000000000000000000000000000000000000";
@@ -822,6 +1192,104 @@ module std::bn254_algebra {
         multi_scalar_mul(&elements, &scalars);
     }
 
+    #[test_only]
+    /// The x-coordinate 4 has no `G1` point, since `4^3 + 3` is not a square in `Fq`.
+    const G1_NOT_ON_CURVE_SERIALIZED_COMP: vector<u8> = x"0400000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    /// The x-coordinate 3 has no `G2` point, since `3^3 + b'` is not a square in `Fq2`.
+    const G2_NOT_ON_CURVE_SERIALIZED_COMP: vector<u8> = x"03000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    /// A point with x-coordinate 1 on the `G2` twist curve, outside of the order-`r` subgroup.
+    const G2_NOT_IN_SUBGROUP_SERIALIZED_COMP: vector<u8> = x"01000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+
+    #[test(fx = @std)]
+    fun test_deserialize_checked(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        assert!(eq(&one<G1>(), &deserialize_checked<G1, FormatG1Compr>(&G1_GENERATOR_SERIALIZED_COMP)), 1);
+        assert!(eq(&zero<G1>(), &deserialize_checked<G1, FormatG1Compr>(&G1_INF_SERIALIZED_COMP)), 1);
+        let point_minus_7g = deserialize_checked<G1, FormatG1Compr>(&G1_GENERATOR_MUL_BY_7_NEG_SERIALIZED_COMP);
+        assert!(G1_GENERATOR_MUL_BY_7_NEG_SERIALIZED_COMP == serialize<G1, FormatG1Compr>(&point_minus_7g), 1);
+        assert!(eq(&one<G2>(), &deserialize_checked<G2, FormatG2Compr>(&G2_GENERATOR_SERIALIZED_COMP)), 1);
+        assert!(eq(&zero<G2>(), &deserialize_checked<G2, FormatG2Compr>(&G2_INF_SERIALIZED_COMP)), 1);
+        let point_minus_7g = deserialize_checked<G2, FormatG2Compr>(&G2_GENERATOR_MUL_BY_7_NEG_SERIALIZED_COMP);
+        assert!(G2_GENERATOR_MUL_BY_7_NEG_SERIALIZED_COMP == serialize<G2, FormatG2Compr>(&point_minus_7g), 1);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x0c0000, location = aptos_std::crypto_algebra)]
+    fun test_deserialize_checked_should_abort_when_not_enabled(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        std::features::change_feature_flags_for_testing(&fx, vector[], vector[std::features::get_algebra_deserialize_checked_feature()]);
+        deserialize_checked<G1, FormatG1Compr>(&G1_GENERATOR_SERIALIZED_COMP);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010004, location = aptos_std::crypto_algebra)]
+    fun test_deserialize_checked_should_abort_on_wrong_size(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        deserialize_checked<G1, FormatG1Compr>(&G1_GENERATOR_SERIALIZED_UNCOMP);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010004, location = aptos_std::crypto_algebra)]
+    fun test_deserialize_checked_should_abort_on_non_canonical_x(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        // `x = q` with no flags set.
+        deserialize_checked<G1, FormatG1Compr>(&x"47fd7cd8168c203c8dca7168916a81975d588181b64550b829a031e1724e6430");
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010005, location = aptos_std::crypto_algebra)]
+    fun test_deserialize_checked_should_abort_on_g1_point_not_on_curve(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        deserialize_checked<G1, FormatG1Compr>(&G1_NOT_ON_CURVE_SERIALIZED_COMP);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010005, location = aptos_std::crypto_algebra)]
+    fun test_deserialize_checked_should_abort_on_g2_point_not_on_curve(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        deserialize_checked<G2, FormatG2Compr>(&G2_NOT_ON_CURVE_SERIALIZED_COMP);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010006, location = aptos_std::crypto_algebra)]
+    fun test_deserialize_checked_should_abort_on_g2_point_not_in_subgroup(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        // `deserialize()` also rejects these bytes, but returns none without saying why.
+        assert!(std::option::is_none(&deserialize<G2, FormatG2Compr>(&G2_NOT_IN_SUBGROUP_SERIALIZED_COMP)), 1);
+        deserialize_checked<G2, FormatG2Compr>(&G2_NOT_IN_SUBGROUP_SERIALIZED_COMP);
+    }
+
+    #[test(fx = @std)]
+    fun test_point_from_coordinates(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        // The G1 generator is `(1, 2)`.
+        let x = x"0100000000000000000000000000000000000000000000000000000000000000";
+        let y = x"0200000000000000000000000000000000000000000000000000000000000000";
+        assert!(eq(&one<G1>(), &point_from_coordinates_for_testing<G1>(&x, &y)), 1);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010005, location = aptos_std::crypto_algebra)]
+    fun test_point_from_coordinates_should_abort_on_g1_point_not_on_curve(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        // `3^2 != 1^3 + 3`.
+        let x = x"0100000000000000000000000000000000000000000000000000000000000000";
+        let y = x"0300000000000000000000000000000000000000000000000000000000000000";
+        point_from_coordinates_for_testing<G1>(&x, &y);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010006, location = aptos_std::crypto_algebra)]
+    fun test_point_from_coordinates_should_abort_on_g2_point_not_in_subgroup(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        // `(1, y)` is on the twist but not in the prime-order subgroup, like `G2_NOT_IN_SUBGROUP_SERIALIZED_COMP`.
+        let x = x"01000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+        let y = x"bbf131051c874babe930982ce570ddaa515a82db8f72e2f872f081531d116928a448e02da88d7f1b51d7b40c797f8c99874193a1706e843608ead93e9571120d";
+        point_from_coordinates_for_testing<G2>(&x, &y);
+    }
+
     #[test_only]
     /// The maximum number of `G1` elements that can be created in a transaction,
     /// calculated by the current memory limit (1MB) and the in-mem G1 representation size (96 bytes per element).
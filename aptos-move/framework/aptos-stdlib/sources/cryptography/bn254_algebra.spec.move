@@ -0,0 +1,21 @@
+spec std::bn254_algebra {
+    spec bn254_fr_modulus_internal(): u256 {
+        pragma opaque;
+    }
+
+    spec bn254_fq_modulus_internal(): u256 {
+        pragma opaque;
+    }
+
+    spec bn254_fr_to_bytes_internal(x: u256, big_endian: bool): vector<u8> {
+        pragma opaque;
+    }
+
+    spec bn254_fr_from_bytes_internal(bytes: vector<u8>, big_endian: bool): (bool, u256) {
+        pragma opaque;
+    }
+
+    spec bn254_fr_from_transcript_internal(bytes: vector<u8>): u256 {
+        pragma opaque;
+    }
+}
@@ -42,6 +42,11 @@ module aptos_std::crypto_algebra {
     const E_NOT_IMPLEMENTED: u64 = 1;
     const E_NON_EQUAL_LENGTHS: u64 = 2;
     const E_TOO_MUCH_MEMORY_USED: u64 = 3;
+    const E_MALFORMED_POINT_ENCODING: u64 = 4;
+    const E_POINT_NOT_ON_CURVE: u64 = 5;
+    const E_POINT_NOT_IN_SUBGROUP: u64 = 6;
+    const E_NON_CANONICAL_ELEMENT: u64 = 7;
+    const E_NOT_IN_CYCLOTOMIC_SUBGROUP: u64 = 8;
 
     /// This struct represents an element of a structure `S`.
     struct Element<phantom S> has copy, drop {
@@ -82,6 +87,26 @@ module aptos_std::crypto_algebra {
         }
     }
 
+    /// Check if `x` is the additive identity of field `S`, or the identity of group `S`, i.e., `eq(x, &zero<S>())`.
+    /// Useful as the acceptance check of a verifier, e.g., on the result of a multi-scalar multiplication.
+    ///
+    /// NOTE: Currently only the BN254 structures are supported.
+    public fun is_zero<S>(x: &Element<S>): bool {
+        abort_unless_cryptography_algebra_natives_enabled();
+        abort_unless_native_enabled(features::algebra_is_zero_is_one_enabled());
+        is_zero_internal<S>(x.handle)
+    }
+
+    /// Check if `x` is the multiplicative identity of field `S`, i.e., `eq(x, &one<S>())`.
+    /// Useful as the acceptance check of a verifier, e.g., on the output of a final exponentiation.
+    ///
+    /// NOTE: Currently only `bn254_algebra::Fr`, `bn254_algebra::Fq` and `bn254_algebra::Fq12` are supported.
+    public fun is_one<S>(x: &Element<S>): bool {
+        abort_unless_cryptography_algebra_natives_enabled();
+        abort_unless_native_enabled(features::algebra_is_zero_is_one_enabled());
+        is_one_internal<S>(x.handle)
+    }
+
     /// Compute `-x` for an element `x` of a structure `S`.
     public fun neg<S>(x: &Element<S>): Element<S> {
         abort_unless_cryptography_algebra_natives_enabled();
@@ -135,6 +160,35 @@ module aptos_std::crypto_algebra {
         }
     }
 
+    /// Compute `x^e` for an element `x` of a field `F` and a `u64` exponent `e`, e.g., `x^3` in a hash round,
+    /// instead of chaining `sqr` and `mul` calls. The cost grows with the bit length of `e`.
+    ///
+    /// NOTE: Supported for every field in `aptos_std`: `Fr` and `Fq` of each curve,
+    /// `bls12381_algebra::Fq2`, `bls12381_algebra::Fq12` and `bn254_algebra::Fq12`.
+    public fun pow_u64<F>(x: &Element<F>, e: u64): Element<F> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        abort_unless_native_enabled(features::algebra_pow_u64_enabled());
+        Element<F> {
+            handle: pow_u64_internal<F>(x.handle, e)
+        }
+    }
+
+    /// Compute `x^e` for an element `x` of the cyclotomic subgroup of an extension field `F`,
+    /// such as a pairing output upcast to `F`. Faster and cheaper than repeated `sqr` and `mul`,
+    /// which makes it useful for the final exponentiation in custom pairing arithmetic.
+    ///
+    /// `x` must be in the cyclotomic subgroup: aborts with `std::error::invalid_argument(E_NOT_IN_CYCLOTOMIC_SUBGROUP)`
+    /// otherwise, e.g., for an arbitrary `Fq12` element that is not a pairing output.
+    ///
+    /// NOTE: Currently only `bn254_algebra::Fq12` is supported.
+    public fun fq12_cyclotomic_pow<F>(x: &Element<F>, e: u64): Element<F> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        abort_unless_native_enabled(features::bn254_fq12_cyclotomic_pow_enabled());
+        Element<F> {
+            handle: fq12_cyclotomic_pow_internal<F>(x.handle, e)
+        }
+    }
+
     /// Try computing `x^(-1)` for an element `x` of a structure `S`.
     /// Return none if `x` does not have a multiplicative inverse in the structure `S`
     /// (e.g., when `S` is a field, and `x` is zero).
@@ -217,6 +271,21 @@ module aptos_std::crypto_algebra {
         }
     }
 
+    /// Deserialize a compressed curve point of group `S` using a given serialization format `F`,
+    /// checking that it is on the curve and in the prime-order subgroup.
+    ///
+    /// Unlike `deserialize()`, which returns none for any invalid input, this reports why the input was rejected.
+    /// Abort with code `std::error::invalid_argument(E_MALFORMED_POINT_ENCODING)` if the bytes are not a valid encoding,
+    /// `std::error::invalid_argument(E_POINT_NOT_ON_CURVE)` if no curve point has the encoded x-coordinate, and
+    /// `std::error::invalid_argument(E_POINT_NOT_IN_SUBGROUP)` if the point is not in the prime-order subgroup.
+    ///
+    /// Currently supported for the compressed formats of BN254 `G1` and `G2`.
+    public fun deserialize_checked<S, F>(bytes: &vector<u8>): Element<S> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        abort_unless_native_enabled(features::algebra_deserialize_checked_enabled());
+        Element<S> { handle: deserialize_checked_internal<S, F>(bytes) }
+    }
+
     /// Serialize an element of an algebraic structure `S` to a byte array using a given serialization format `F`.
     public fun serialize<S, F>(element: &Element<S>): vector<u8> {
         abort_unless_cryptography_algebra_natives_enabled();
@@ -271,6 +340,19 @@ module aptos_std::crypto_algebra {
         }
     }
 
+    #[test_only]
+    /// Construct an element of a group `G` from its affine coordinates `x` and `y`,
+    /// each in the uncompressed serialization of the base field.
+    ///
+    /// Aborts with `std::error::invalid_argument(E_POINT_NOT_ON_CURVE)` if `(x, y)` is not on the curve,
+    /// and with `std::error::invalid_argument(E_POINT_NOT_IN_SUBGROUP)` if it is not in the prime-order subgroup.
+    public fun point_from_coordinates_for_testing<G>(x: &vector<u8>, y: &vector<u8>): Element<G> {
+        abort_unless_cryptography_algebra_natives_enabled();
+        Element<G> {
+            handle: point_from_coordinates_internal<G>(x, y)
+        }
+    }
+
     //
     // (Public functions end here.)
     // Private functions begin.
@@ -281,9 +363,28 @@ module aptos_std::crypto_algebra {
         abort(std::error::not_implemented(0))
     }
 
+    /// Natives added after the initial release of this module have their own feature flag, passed here as `enabled`.
+    fun abort_unless_native_enabled(enabled: bool) {
+        if (enabled) return;
+        abort(std::error::not_implemented(0))
+    }
+
     #[test_only]
     public fun enable_cryptography_algebra_natives(fx: &signer) {
-        std::features::change_feature_flags_for_testing(fx, vector[std::features::get_cryptography_algebra_natives_feature()], vector[]);
+        std::features::change_feature_flags_for_testing(fx, vector[
+            std::features::get_cryptography_algebra_natives_feature(),
+            std::features::get_secp256k1_secp256r1_structures_feature(),
+            std::features::get_grumpkin_structures_feature(),
+            std::features::get_pasta_structures_feature(),
+            std::features::get_bls12_381_base_field_structures_feature(),
+            std::features::get_algebra_deserialize_checked_feature(),
+            std::features::get_bn254_fq12_cyclotomic_pow_feature(),
+            std::features::get_bn254_moduli_feature(),
+            std::features::get_bn254_fr_bytes_feature(),
+            std::features::get_bn254_fr_from_transcript_feature(),
+            std::features::get_algebra_is_zero_is_one_feature(),
+            std::features::get_algebra_pow_u64_feature(),
+        ], vector[]);
     }
 
     fun handles_from_elements<S>(elements: &vector<Element<S>>): vector<u64> {
@@ -310,15 +411,22 @@ module aptos_std::crypto_algebra {
 
     native fun add_internal<S>(handle_1: u64, handle_2: u64): u64;
     native fun deserialize_internal<S, F>(bytes: &vector<u8>): (bool, u64);
+    native fun deserialize_checked_internal<S, F>(bytes: &vector<u8>): u64;
     native fun div_internal<F>(handle_1: u64, handle_2: u64): (bool, u64);
     native fun double_internal<G>(element_handle: u64): u64;
     native fun downcast_internal<L,S>(handle: u64): (bool, u64);
     native fun from_u64_internal<S>(value: u64): u64;
+    native fun fq12_cyclotomic_pow_internal<F>(handle: u64, exponent: u64): u64;
+    native fun pow_u64_internal<F>(handle: u64, exponent: u64): u64;
     native fun eq_internal<S>(handle_1: u64, handle_2: u64): bool;
+    native fun is_one_internal<S>(handle: u64): bool;
+    native fun is_zero_internal<S>(handle: u64): bool;
     native fun hash_to_internal<S, H>(dst: &vector<u8>, bytes: &vector<u8>): u64;
     native fun inv_internal<F>(handle: u64): (bool, u64);
     #[test_only]
     native fun rand_insecure_internal<S>(): u64;
+    #[test_only]
+    native fun point_from_coordinates_internal<G>(x: &vector<u8>, y: &vector<u8>): u64;
     native fun mul_internal<F>(handle_1: u64, handle_2: u64): u64;
     native fun multi_pairing_internal<G1,G2,Gt>(g1_handles: vector<u64>, g2_handles: vector<u64>): u64;
     native fun multi_scalar_mul_internal<G, S>(element_handles: vector<u64>, scalar_handles: vector<u64>): u64;
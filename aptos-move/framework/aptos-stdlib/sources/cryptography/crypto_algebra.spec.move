@@ -13,6 +13,10 @@ spec aptos_std::crypto_algebra {
         pragma opaque;
     }
 
+    spec deserialize_checked_internal<S, F>(bytes: &vector<u8>): u64 {
+        pragma opaque;
+    }
+
     spec div_internal<F>(handle_1: u64, handle_2: u64): (bool, u64) {
         pragma opaque;
     }
@@ -21,6 +25,14 @@ spec aptos_std::crypto_algebra {
         pragma opaque;
     }
 
+    spec fq12_cyclotomic_pow_internal<F>(handle: u64, exponent: u64): u64 {
+        pragma opaque;
+    }
+
+    spec pow_u64_internal<F>(handle: u64, exponent: u64): u64 {
+        pragma opaque;
+    }
+
     spec downcast_internal<L,S>(handle: u64): (bool, u64) {
         pragma opaque;
     }
@@ -33,6 +45,14 @@ spec aptos_std::crypto_algebra {
         pragma opaque;
     }
 
+    spec is_one_internal<S>(handle: u64): bool {
+        pragma opaque;
+    }
+
+    spec is_zero_internal<S>(handle: u64): bool {
+        pragma opaque;
+    }
+
     spec hash_to_internal<S, H>(dst: &vector<u8>, bytes: &vector<u8>): u64 {
         pragma opaque;
     }
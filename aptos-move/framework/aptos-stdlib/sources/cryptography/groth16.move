@@ -0,0 +1,145 @@
+/// This module implements a Groth16 zkSNARK verifier over the BN254 curve.
+///
+/// It verifies a proof against a verifying key and public inputs in a single native call, instead of composing the
+/// multi-scalar multiplication and pairing operations of `aptos_std::crypto_algebra` in Move.
+///
+/// The verifying key and the proof use the compressed `CanonicalSerialize` encodings of `ark-groth16` 0.4:
+/// - A verifying key is `alpha_g1 || beta_g2 || gamma_g2 || delta_g2 || n || gamma_abc_g1[0] || ... || gamma_abc_g1[n-1]`,
+///   where `n` is the number of public inputs plus one, as an 8-byte little-endian integer.
+/// - A proof is `a || b || c`.
+///
+/// `G1` and `G2` points are 32 and 64 bytes, respectively, as in `FormatG1Compr` and `FormatG2Compr` of
+/// `aptos_std::bn254_algebra`. Points must be on the curve and in the prime-order subgroup.
+module aptos_std::groth16 {
+    use std::error;
+    use std::features;
+
+    //
+    // Error codes
+    //
+
+    /// The verifying key is not a valid encoding.
+    const E_MALFORMED_VERIFYING_KEY: u64 = 1;
+
+    /// The proof is not a valid encoding.
+    const E_MALFORMED_PROOF: u64 = 2;
+
+    /// The number of public inputs does not match the verifying key.
+    const E_WRONG_NUMBER_OF_PUBLIC_INPUTS: u64 = 3;
+
+    /// A public input is not smaller than the order of the BN254 scalar field.
+    const E_PUBLIC_INPUT_NOT_CANONICAL: u64 = 4;
+
+    /// The native functions have not been rolled out yet.
+    const E_NATIVE_FUN_NOT_AVAILABLE: u64 = 5;
+
+    //
+    // Public functions
+    //
+
+    /// Verifies a Groth16 proof over BN254 for the given public inputs, which are elements of the BN254 scalar field.
+    /// Returns false if the proof does not verify.
+    ///
+    /// Aborts with `error::invalid_argument(E_MALFORMED_VERIFYING_KEY)` if `vk` is not a valid verifying key,
+    /// `error::invalid_argument(E_MALFORMED_PROOF)` if `proof` is not a valid proof,
+    /// `error::invalid_argument(E_WRONG_NUMBER_OF_PUBLIC_INPUTS)` if `vk` is for a different number of public inputs, and
+    /// `error::invalid_argument(E_PUBLIC_INPUT_NOT_CANONICAL)` if a public input is not smaller than the field order.
+    ///
+    /// The gas cost grows linearly with the number of public inputs.
+    public fun verify_groth16_bn254(vk: vector<u8>, public_inputs: vector<u256>, proof: vector<u8>): bool {
+        assert!(features::groth16_bn254_enabled(), error::invalid_state(E_NATIVE_FUN_NOT_AVAILABLE));
+        verify_groth16_bn254_internal(vk, public_inputs, proof)
+    }
+
+    //
+    // Native functions
+    //
+
+    native fun verify_groth16_bn254_internal(vk: vector<u8>, public_inputs: vector<u256>, proof: vector<u8>): bool;
+
+    //
+    // Testing
+    //
+
+    // The key and the proof below are for a circuit proving knowledge of `w` such that `w^3 + x = y`, for public inputs
+    // `x` and `y`. They were generated with `Groth16::<Bn254>::circuit_specific_setup` and `Groth16::<Bn254>::prove`
+    // of `ark-groth16` 0.4, for `x = 42` and `w = 3`.
+    #[test_only]
+    const VK: vector<u8> = x"e57314eb0d2d4acef7a0b56306a4ac1dc99b9a1dc15a34dc549a052171bd981b1e6533af92c383be56b39f0520f0c3f5713f404cc505f4887a88224fbf49562da2f948129e080d367595fcc2f8a6beee2c088f4e77fdfdb9edaaeb4b407d381617e42ded924236cbb7a82ba74b9ae3198aeef633e290a9931ed396a6e6109d1abb8615f6e3dfff68ed2be32ce4035b12e85792795c2f87bc0d2999cf9d2a81224795e7b72c7be59554ce1157ac2db49d2757e81bae97505a0d81ccbe88beb12f3e2e2c16d42cc821a62663a6cd59dcfa621b9aa7472df54a6f13500c1dea611e03000000000000008af8e514b417bb84d5956e51de5c66b7c6f6f3ba671994d6cc9477918a496d8a9dc4067eb0345594c1662af21fcf6d54ea5205d142d8c46349371d81f4e6d29bbb5724653c686d634dba0e02b703efa47e90e5f981c2b6865d06db15a264db27";
+    #[test_only]
+    const PROOF: vector<u8> = x"eb23a8fa6cfd32792247017fb2e7a59b71dbcb0cc7e4843a2e73d9224b818118e451505235fd8a508fd93316432e93a56192b848ce75823f2afd155ee9e7c024c61b09cb1365e88406c77c8ca721bd1f1b79f4bba9568942ce1eb8848ff5d1268d2bc468470ef53a063f96186465b9ef843c3b7b0ed309a06c7d51cb637b5b01";
+    #[test_only]
+    const PUBLIC_INPUT_0: u256 = 42;
+    #[test_only]
+    const PUBLIC_INPUT_1: u256 = 69;
+    #[test_only]
+    /// `PROOF` with `a` replaced by a different valid point.
+    const TAMPERED_PROOF: vector<u8> = x"e6e747e40093083557696c2b8b6febfded828309507de0d2f9a1b41e015aed22e451505235fd8a508fd93316432e93a56192b848ce75823f2afd155ee9e7c024c61b09cb1365e88406c77c8ca721bd1f1b79f4bba9568942ce1eb8848ff5d1268d2bc468470ef53a063f96186465b9ef843c3b7b0ed309a06c7d51cb637b5b01";
+    #[test_only]
+    /// `PROOF` with `a` replaced by an x-coordinate that has no point on the curve.
+    const PROOF_NOT_ON_CURVE: vector<u8> = x"0400000000000000000000000000000000000000000000000000000000000000e451505235fd8a508fd93316432e93a56192b848ce75823f2afd155ee9e7c024c61b09cb1365e88406c77c8ca721bd1f1b79f4bba9568942ce1eb8848ff5d1268d2bc468470ef53a063f96186465b9ef843c3b7b0ed309a06c7d51cb637b5b01";
+    #[test_only]
+    /// The order of the BN254 scalar field.
+    const R: u256 = 21888242871839275222246405745257275088548364400416034343698204186575808495617;
+
+    #[test_only]
+    fun enable_groth16(fx: &signer) {
+        features::change_feature_flags_for_testing(fx, vector[features::get_groth16_bn254_feature()], vector[]);
+    }
+
+    #[test(fx = @std)]
+    fun test_valid_proof(fx: signer) {
+        enable_groth16(&fx);
+        assert!(verify_groth16_bn254(VK, vector[PUBLIC_INPUT_0, PUBLIC_INPUT_1], PROOF), 1);
+    }
+
+    #[test(fx = @std)]
+    fun test_tampered_proof(fx: signer) {
+        enable_groth16(&fx);
+        assert!(!verify_groth16_bn254(VK, vector[PUBLIC_INPUT_0, PUBLIC_INPUT_1], TAMPERED_PROOF), 1);
+    }
+
+    #[test(fx = @std)]
+    fun test_wrong_public_input(fx: signer) {
+        enable_groth16(&fx);
+        assert!(!verify_groth16_bn254(VK, vector[PUBLIC_INPUT_0 + 1, PUBLIC_INPUT_1], PROOF), 1);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010001, location = Self)]
+    fun test_truncated_vk(fx: signer) {
+        enable_groth16(&fx);
+        let vk = VK;
+        std::vector::pop_back(&mut vk);
+        verify_groth16_bn254(vk, vector[PUBLIC_INPUT_0, PUBLIC_INPUT_1], PROOF);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010002, location = Self)]
+    fun test_proof_not_on_curve(fx: signer) {
+        enable_groth16(&fx);
+        verify_groth16_bn254(VK, vector[PUBLIC_INPUT_0, PUBLIC_INPUT_1], PROOF_NOT_ON_CURVE);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010003, location = Self)]
+    fun test_wrong_number_of_public_inputs(fx: signer) {
+        enable_groth16(&fx);
+        verify_groth16_bn254(VK, vector[PUBLIC_INPUT_0], PROOF);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010004, location = Self)]
+    fun test_public_input_not_canonical(fx: signer) {
+        enable_groth16(&fx);
+        // `R + 42` would be congruent to `PUBLIC_INPUT_0`, if it were reduced.
+        verify_groth16_bn254(VK, vector[R + PUBLIC_INPUT_0, PUBLIC_INPUT_1], PROOF);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x030005, location = Self)]
+    fun test_native_fun_not_available(fx: signer) {
+        features::change_feature_flags_for_testing(&fx, vector[], vector[features::get_groth16_bn254_feature()]);
+        verify_groth16_bn254(VK, vector[PUBLIC_INPUT_0, PUBLIC_INPUT_1], PROOF);
+    }
+}
@@ -0,0 +1,5 @@
+spec aptos_std::groth16 {
+    spec verify_groth16_bn254_internal(vk: vector<u8>, public_inputs: vector<u256>, proof: vector<u8>): bool {
+        pragma opaque;
+    }
+}
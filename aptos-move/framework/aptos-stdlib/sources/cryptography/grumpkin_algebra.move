@@ -0,0 +1,442 @@
+/// This module defines marker types, constants and test cases for working with the Grumpkin curve
+/// using the generic API defined in `crypto_algebra.move`.
+/// Grumpkin is defined over the scalar field of BN254, and its group order equals the base field modulus of BN254,
+/// so the two curves form a cycle: `grumpkin_algebra::Fr` and `bn254_algebra::Fq` are the same field,
+/// and so are `grumpkin_algebra::Fq` and `bn254_algebra::Fr`.
+/// This makes Grumpkin the standard choice for recursive proof constructions paired with BN254 circuits.
+///
+/// Curve information:
+/// * Base field: q =
+///   21888242871839275222246405745257275088548364400416034343698204186575808495617
+/// * Scalar field: r =
+///   21888242871839275222246405745257275088696311157297823662689037894645226208583
+/// * Curve equation: y^2 = x^3 - 17
+/// * Cofactor: 1
+///
+/// Currently-supported Grumpkin structures include `Fr`, `Fq` and `G1`,
+/// along with their widely-used serialization formats.
+/// `Fr` elements can be converted to and from `bn254_algebra::Fq` elements
+/// with `crypto_algebra::upcast()` and `crypto_algebra::downcast()`, which always succeed.
+///
+/// The curve is not pairing-friendly, so no pairing is defined for it.
+module aptos_std::grumpkin_algebra {
+    //
+    // Marker types + serialization formats begin.
+    //
+
+    /// The finite field $F_r$ that can be used as the scalar field associated with the group `G1`.
+    /// It is the same field as `bn254_algebra::Fq`.
+    struct Fr {}
+
+    /// A serialization format for `Fr` elements,
+    /// where an element is represented by a byte array `b[]` of size 32 with the least significant byte (LSB) coming first.
+    ///
+    /// NOTE: the same format as `bn254_algebra::FormatFqLsb`.
+    struct FormatFrLsb {}
+
+    /// A serialization scheme for `Fr` elements,
+    /// where an element is represented by a byte array `b[]` of size 32 with the most significant byte (MSB) coming first.
+    ///
+    /// NOTE: the same format as `bn254_algebra::FormatFqMsb`.
+    struct FormatFrMsb {}
+
+    /// The finite field $F_q$ that can be used as the base field of `G1`.
+    /// It is the same field as `bn254_algebra::Fr`.
+    struct Fq {}
+
+    /// A serialization format for `Fq` elements,
+    /// where an element is represented by a byte array `b[]` of size 32 with the least significant byte (LSB) coming first.
+    ///
+    /// NOTE: the same format as `bn254_algebra::FormatFrLsb`.
+    struct FormatFqLsb {}
+
+    /// A serialization scheme for `Fq` elements,
+    /// where an element is represented by a byte array `b[]` of size 32 with the most significant byte (MSB) coming first.
+    ///
+    /// NOTE: the same format as `bn254_algebra::FormatFrMsb`.
+    struct FormatFqMsb {}
+
+    /// The group constructed by the points on the Grumpkin curve $E(F_q): y^2 = x^3 - 17$ and the point at infinity,
+    /// under the elliptic curve point addition.
+    /// It has a prime order $r$ equal to 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47 (the cofactor is 1),
+    /// so `Fr` is the associated scalar field.
+    /// The generator is $(1, \sqrt{-16})$, the same as in Aztec's barretenberg.
+    struct G1 {}
+
+    /// A serialization scheme for `G1` elements derived from arkworks.rs.
+    ///
+    /// Below is the serialization procedure that takes a `G1` element `p` and outputs a byte array of size N=64.
+    /// 1. Let `(x,y)` be the coordinates of `p` if `p` is on the curve, or `(0,0)` otherwise.
+    /// 1. Serialize `x` and `y` into `b_x[]` and `b_y[]` respectively using `FormatFqLsb`.
+    /// 1. Concatenate `b_x[]` and `b_y[]` into `b[]`.
+    /// 1. If `p` is the point at infinity, set the infinity bit: `b[N-1]: = b[N-1] | 0b0100_0000`.
+    /// 1. If `y > -y`, set the lexicographical bit:  `b[N-1]: = b[N-1] | 0b1000_0000`.
+    /// 1. Return `b[]`.
+    ///
+    /// Below is the deserialization procedure that takes a byte array `b[]` and outputs either a `G1` element or none.
+    /// 1. If the size of `b[]` is not N, return none.
+    /// 1. Compute the infinity flag as `b[N-1] & 0b0100_0000 != 0`.
+    /// 1. If the infinity flag is set, return the point at infinity.
+    /// 1. Deserialize `[b[0], b[1], ..., b[N/2-1]]` to `x` using `FormatFqLsb`. If `x` is none, return none.
+    /// 1. Deserialize `[b[N/2], ..., b[N] & 0b0011_1111]` to `y` using `FormatFqLsb`. If `y` is none, return none.
+    /// 1. Check if `(x,y)` is on curve `E`. If not, return none.
+    /// 1. Return `(x,y)`.
+    struct FormatG1Uncompr {}
+
+    /// A serialization scheme for `G1` elements derived from arkworks.rs.
+    ///
+    /// Below is the serialization procedure that takes a `G1` element `p` and outputs a byte array of size N=32.
+    /// 1. Let `(x,y)` be the coordinates of `p` if `p` is on the curve, or `(0,0)` otherwise.
+    /// 1. Serialize `x` into `b[]` using `FormatFqLsb`.
+    /// 1. If `p` is the point at infinity, set the infinity bit: `b[N-1]: = b[N-1] | 0b0100_0000`.
+    /// 1. If `y > -y`, set the lexicographical flag: `b[N-1] := b[N-1] | 0b1000_0000`.
+    /// 1. Return `b[]`.
+    ///
+    /// Below is the deserialization procedure that takes a byte array `b[]` and outputs either a `G1` element or none.
+    /// 1. If the size of `b[]` is not N, return none.
+    /// 1. Compute the infinity flag as `b[N-1] & 0b0100_0000 != 0`.
+    /// 1. If the infinity flag is set, return the point at infinity.
+    /// 1. Compute the lexicographical flag as `b[N-1] & 0b1000_0000 != 0`.
+    /// 1. Deserialize `[b[0], b[1], ..., b[N-1] & 0b0011_1111]` to `x` using `FormatFqLsb`. If `x` is none, return none.
+    /// 1. Solve the curve equation with `x` for `y`. If no such `y` exists, return none.
+    /// 1. Let `y'` be `max(y,-y)` if the lexicographical flag is set, or `min(y,-y)` otherwise.
+    /// 1. Return `(x,y')`.
+    struct FormatG1Compr {}
+
+    // Tests begin.
+
+    #[test_only]
+    fun rand_vector<S>(num: u64): vector<Element<S>> {
+        let elements = vector[];
+        while (num > 0) {
+            std::vector::push_back(&mut elements, rand_insecure<S>());
+            num = num - 1;
+        };
+        elements
+    }
+
+    #[test_only]
+    use aptos_std::crypto_algebra::{Element, zero, one, from_u64, eq, deserialize, serialize, neg, add, sub, mul, div, inv, rand_insecure, sqr, pow_u64, order, scalar_mul, multi_scalar_mul, double, upcast, downcast, enable_cryptography_algebra_natives};
+
+    #[test_only]
+    const R_SERIALIZED: vector<u8> = x"47fd7cd8168c203c8dca7168916a81975d588181b64550b829a031e1724e6430";
+    #[test_only]
+    const FR_VAL_0_SERIALIZED_LSB: vector<u8> = x"0000000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FR_VAL_1_SERIALIZED_LSB: vector<u8> = x"0100000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FR_VAL_7_SERIALIZED_LSB: vector<u8> = x"0700000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FR_VAL_7_SERIALIZED_MSB: vector<u8> = x"0000000000000000000000000000000000000000000000000000000000000007";
+    #[test_only]
+    const FR_VAL_7_NEG_SERIALIZED_LSB: vector<u8> = x"40fd7cd8168c203c8dca7168916a81975d588181b64550b829a031e1724e6430";
+
+    #[test(fx = @std)]
+    fun test_fr(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // Constants.
+        assert!(R_SERIALIZED == order<Fr>(), 1);
+
+        // Serialization/deserialization.
+        let val_0 = zero<Fr>();
+        let val_1 = one<Fr>();
+        assert!(FR_VAL_0_SERIALIZED_LSB == serialize<Fr, FormatFrLsb>(&val_0), 1);
+        assert!(FR_VAL_1_SERIALIZED_LSB == serialize<Fr, FormatFrLsb>(&val_1), 1);
+        let val_7 = from_u64<Fr>(7);
+        let val_7_2nd = std::option::extract(&mut deserialize<Fr, FormatFrLsb>(&FR_VAL_7_SERIALIZED_LSB));
+        let val_7_3rd = std::option::extract(&mut deserialize<Fr, FormatFrMsb>(&FR_VAL_7_SERIALIZED_MSB));
+        assert!(eq(&val_7, &val_7_2nd), 1);
+        assert!(eq(&val_7, &val_7_3rd), 1);
+        assert!(FR_VAL_7_SERIALIZED_LSB == serialize<Fr, FormatFrLsb>(&val_7), 1);
+        assert!(FR_VAL_7_SERIALIZED_MSB == serialize<Fr, FormatFrMsb>(&val_7), 1);
+
+        // Deserialization should fail if given a byte array of right size but the value is not a member.
+        assert!(std::option::is_none(&deserialize<Fr, FormatFrLsb>(&x"47fd7cd8168c203c8dca7168916a81975d588181b64550b829a031e1724e6430")), 1);
+        assert!(std::option::is_none(&deserialize<Fr, FormatFrMsb>(&x"30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47")), 1);
+
+        // Deserialization should fail if given a byte array of wrong size.
+        assert!(std::option::is_none(&deserialize<Fr, FormatFrLsb>(&x"070000000000000000000000000000000000000000000000000000000000000000")), 1);
+        assert!(std::option::is_none(&deserialize<Fr, FormatFrMsb>(&x"000000000000000000000000000000000000000000000000000000000000000007")), 1);
+        assert!(std::option::is_none(&deserialize<Fr, FormatFrLsb>(&x"ffff")), 1);
+        assert!(std::option::is_none(&deserialize<Fr, FormatFrMsb>(&x"ffff")), 1);
+
+        // Negation.
+        let val_minus_7 = neg(&val_7);
+        assert!(FR_VAL_7_NEG_SERIALIZED_LSB == serialize<Fr, FormatFrLsb>(&val_minus_7), 1);
+
+        // Addition.
+        let val_9 = from_u64<Fr>(9);
+        let val_2 = from_u64<Fr>(2);
+        assert!(eq(&val_2, &add(&val_minus_7, &val_9)), 1);
+
+        // Subtraction.
+        assert!(eq(&val_9, &sub(&val_2, &val_minus_7)), 1);
+
+        // Multiplication.
+        let val_63 = from_u64<Fr>(63);
+        assert!(eq(&val_63, &mul(&val_7, &val_9)), 1);
+
+        // division.
+        let val_0 = from_u64<Fr>(0);
+        assert!(eq(&val_7, &std::option::extract(&mut div(&val_63, &val_9))), 1);
+        assert!(std::option::is_none(&div(&val_63, &val_0)), 1);
+
+        // Inversion.
+        assert!(eq(&val_1, &mul(&val_7, &std::option::extract(&mut inv(&val_7)))), 1);
+        assert!(std::option::is_none(&inv(&val_0)), 1);
+
+        // Squaring.
+        let val_x = rand_insecure<Fr>();
+        assert!(eq(&mul(&val_x, &val_x), &sqr(&val_x)), 1);
+    }
+
+    #[test(fx = @std)]
+    fun test_pow_u64(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        let x = rand_insecure<Fr>();
+        assert!(eq(&one<Fr>(), &pow_u64(&x, 0)), 1);
+        assert!(eq(&x, &pow_u64(&x, 1)), 1);
+        assert!(eq(&mul(&x, &sqr(&x)), &pow_u64(&x, 3)), 1);
+        assert!(eq(&from_u64<Fq>(1024), &pow_u64(&from_u64<Fq>(2), 10)), 1);
+        let y = rand_insecure<Fq>();
+        assert!(eq(&pow_u64(&y, 18446744073709551615), &mul(&pow_u64(&y, 18446744073709551614), &y)), 1);
+    }
+
+    #[test_only]
+    const Q_SERIALIZED: vector<u8> = x"010000f093f5e1439170b97948e833285d588181b64550b829a031e1724e6430";
+    #[test_only]
+    const FQ_VAL_0_SERIALIZED_LSB: vector<u8> = x"0000000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FQ_VAL_1_SERIALIZED_LSB: vector<u8> = x"0100000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FQ_VAL_7_SERIALIZED_LSB: vector<u8> = x"0700000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FQ_VAL_7_SERIALIZED_MSB: vector<u8> = x"0000000000000000000000000000000000000000000000000000000000000007";
+    #[test_only]
+    const FQ_VAL_7_NEG_SERIALIZED_LSB: vector<u8> = x"faffffef93f5e1439170b97948e833285d588181b64550b829a031e1724e6430";
+
+    #[test(fx = @std)]
+    fun test_fq(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // Constants.
+        assert!(Q_SERIALIZED == order<Fq>(), 1);
+
+        // Serialization/deserialization.
+        let val_0 = zero<Fq>();
+        let val_1 = one<Fq>();
+        assert!(FQ_VAL_0_SERIALIZED_LSB == serialize<Fq, FormatFqLsb>(&val_0), 1);
+        assert!(FQ_VAL_1_SERIALIZED_LSB == serialize<Fq, FormatFqLsb>(&val_1), 1);
+        let val_7 = from_u64<Fq>(7);
+        let val_7_2nd = std::option::extract(&mut deserialize<Fq, FormatFqLsb>(&FQ_VAL_7_SERIALIZED_LSB));
+        let val_7_3rd = std::option::extract(&mut deserialize<Fq, FormatFqMsb>(&FQ_VAL_7_SERIALIZED_MSB));
+        assert!(eq(&val_7, &val_7_2nd), 1);
+        assert!(eq(&val_7, &val_7_3rd), 1);
+        assert!(FQ_VAL_7_SERIALIZED_LSB == serialize<Fq, FormatFqLsb>(&val_7), 1);
+        assert!(FQ_VAL_7_SERIALIZED_MSB == serialize<Fq, FormatFqMsb>(&val_7), 1);
+
+        // Deserialization should fail if given a byte array of right size but the value is not a member.
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqLsb>(&x"010000f093f5e1439170b97948e833285d588181b64550b829a031e1724e6430")), 1);
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqMsb>(&x"30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001")), 1);
+
+        // Deserialization should fail if given a byte array of wrong size.
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqLsb>(&x"070000000000000000000000000000000000000000000000000000000000000000")), 1);
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqMsb>(&x"000000000000000000000000000000000000000000000000000000000000000007")), 1);
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqLsb>(&x"ffff")), 1);
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqMsb>(&x"ffff")), 1);
+
+        // Negation.
+        let val_minus_7 = neg(&val_7);
+        assert!(FQ_VAL_7_NEG_SERIALIZED_LSB == serialize<Fq, FormatFqLsb>(&val_minus_7), 1);
+
+        // Addition.
+        let val_9 = from_u64<Fq>(9);
+        let val_2 = from_u64<Fq>(2);
+        assert!(eq(&val_2, &add(&val_minus_7, &val_9)), 1);
+
+        // Subtraction.
+        assert!(eq(&val_9, &sub(&val_2, &val_minus_7)), 1);
+
+        // Multiplication.
+        let val_63 = from_u64<Fq>(63);
+        assert!(eq(&val_63, &mul(&val_7, &val_9)), 1);
+
+        // division.
+        let val_0 = from_u64<Fq>(0);
+        assert!(eq(&val_7, &std::option::extract(&mut div(&val_63, &val_9))), 1);
+        assert!(std::option::is_none(&div(&val_63, &val_0)), 1);
+
+        // Inversion.
+        assert!(eq(&val_1, &mul(&val_7, &std::option::extract(&mut inv(&val_7)))), 1);
+        assert!(std::option::is_none(&inv(&val_0)), 1);
+
+        // Squaring.
+        let val_x = rand_insecure<Fq>();
+        assert!(eq(&mul(&val_x, &val_x), &sqr(&val_x)), 1);
+    }
+
+    #[test_only]
+    const G1_INF_SERIALIZED_COMP: vector<u8> = x"0000000000000000000000000000000000000000000000000000000000000040";
+    #[test_only]
+    const G1_INF_SERIALIZED_UNCOMP: vector<u8> = x"00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000040";
+    #[test_only]
+    const G1_GENERATOR_SERIALIZED_COMP: vector<u8> = x"0100000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const G1_GENERATOR_SERIALIZED_UNCOMP: vector<u8> = x"01000000000000000000000000000000000000000000000000000000000000002c273f828dc43f83941218f1450d272d635da406755e13cf0200000000000000";
+    #[test_only]
+    const G1_GENERATOR_MUL_BY_7_SERIALIZED_COMP: vector<u8> = x"42289b7b06a8330ae61d9a628f7a182a9c3fdd9a067fa139d0e8a3d69d2b600e";
+    #[test_only]
+    const G1_GENERATOR_MUL_BY_7_SERIALIZED_UNCOMP: vector<u8> = x"42289b7b06a8330ae61d9a628f7a182a9c3fdd9a067fa139d0e8a3d69d2b600ecb14b09c41c1b14252165aef0d57fe0707cb0540c9d23b16bb5c1bdf838ecc14";
+    #[test_only]
+    const G1_GENERATOR_MUL_BY_7_NEG_SERIALIZED_COMP: vector<u8> = x"42289b7b06a8330ae61d9a628f7a182a9c3fdd9a067fa139d0e8a3d69d2b608e";
+    #[test_only]
+    const G1_GENERATOR_MUL_BY_7_NEG_SERIALIZED_UNCOMP: vector<u8> = x"42289b7b06a8330ae61d9a628f7a182a9c3fdd9a067fa139d0e8a3d69d2b600e36eb4f53523430013f5a5f8a3a913520568d7b41ed7214a26e431602efbf979b";
+
+    #[test(fx = @std)]
+    fun test_g1(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // Constants.
+        assert!(R_SERIALIZED == order<G1>(), 1);
+        let point_at_infinity = zero<G1>();
+        let generator = one<G1>();
+
+        // Serialization/deserialization.
+        assert!(G1_GENERATOR_SERIALIZED_UNCOMP == serialize<G1, FormatG1Uncompr>(&generator), 1);
+        assert!(G1_GENERATOR_SERIALIZED_COMP == serialize<G1, FormatG1Compr>(&generator), 1);
+        let generator_from_comp = std::option::extract(&mut deserialize<G1, FormatG1Compr>(&G1_GENERATOR_SERIALIZED_COMP));
+        let generator_from_uncomp = std::option::extract(&mut deserialize<G1, FormatG1Uncompr>(&G1_GENERATOR_SERIALIZED_UNCOMP));
+        assert!(eq(&generator, &generator_from_comp), 1);
+        assert!(eq(&generator, &generator_from_uncomp), 1);
+
+        assert!(G1_INF_SERIALIZED_UNCOMP == serialize<G1, FormatG1Uncompr>(&point_at_infinity), 1);
+        assert!(G1_INF_SERIALIZED_COMP == serialize<G1, FormatG1Compr>(&point_at_infinity), 1);
+        let inf_from_uncomp = std::option::extract(&mut deserialize<G1, FormatG1Uncompr>(&G1_INF_SERIALIZED_UNCOMP));
+        let inf_from_comp = std::option::extract(&mut deserialize<G1, FormatG1Compr>(&G1_INF_SERIALIZED_COMP));
+        assert!(eq(&point_at_infinity, &inf_from_comp), 1);
+        assert!(eq(&point_at_infinity, &inf_from_uncomp), 1);
+
+        let point_7g_from_uncomp = std::option::extract(&mut deserialize<G1, FormatG1Uncompr>(&G1_GENERATOR_MUL_BY_7_SERIALIZED_UNCOMP));
+        let point_7g_from_comp = std::option::extract(&mut deserialize<G1, FormatG1Compr>(&G1_GENERATOR_MUL_BY_7_SERIALIZED_COMP));
+        assert!(eq(&point_7g_from_comp, &point_7g_from_uncomp), 1);
+
+        // Deserialization should fail if given a valid point in (Fq,Fq) but not on the curve.
+        assert!(std::option::is_none(&deserialize<G1, FormatG1Uncompr>(&x"01000000000000000000000000000000000000000000000000000000000000002d273f828dc43f83941218f1450d272d635da406755e13cf0200000000000000")), 1);
+        assert!(std::option::is_none(&deserialize<G1, FormatG1Compr>(&x"0300000000000000000000000000000000000000000000000000000000000000")), 1);
+
+        // Deserialization should fail if given an invalid point (x not in Fq).
+        assert!(std::option::is_none(&deserialize<G1, FormatG1Compr>(&x"010000f093f5e1439170b97948e833285d588181b64550b829a031e1724e6430")), 1);
+
+        // Deserialization should fail if given a byte array of wrong size.
+        assert!(std::option::is_none(&deserialize<G1, FormatG1Uncompr>(&x"01000000000000000000000000000000000000000000000000000000000000002c273f828dc43f83941218f1450d272d635da406755e13cf020000000000000000")), 1);
+        assert!(std::option::is_none(&deserialize<G1, FormatG1Compr>(&x"010000000000000000000000000000000000000000000000000000000000000000")), 1);
+
+        // Scalar multiplication.
+        let scalar_7 = from_u64<Fr>(7);
+        let point_7g_calc = scalar_mul(&generator, &scalar_7);
+        assert!(eq(&point_7g_calc, &point_7g_from_comp), 1);
+        assert!(G1_GENERATOR_MUL_BY_7_SERIALIZED_UNCOMP == serialize<G1, FormatG1Uncompr>(&point_7g_calc), 1);
+        assert!(G1_GENERATOR_MUL_BY_7_SERIALIZED_COMP == serialize<G1, FormatG1Compr>(&point_7g_calc), 1);
+
+        // Multi-scalar multiplication.
+        let num_entries = 1;
+        while (num_entries < 10) {
+            let scalars = rand_vector<Fr>(num_entries);
+            let elements = rand_vector<G1>(num_entries);
+
+            let expected = zero<G1>();
+            let i = 0;
+            while (i < num_entries) {
+                let element = std::vector::borrow(&elements, i);
+                let scalar = std::vector::borrow(&scalars, i);
+                expected = add(&expected, &scalar_mul(element, scalar));
+                i = i + 1;
+            };
+
+            let actual = multi_scalar_mul(&elements, &scalars);
+            assert!(eq(&expected, &actual), 1);
+
+            num_entries = num_entries + 1;
+        };
+
+        // Doubling.
+        let scalar_2 = from_u64<Fr>(2);
+        let point_2g = scalar_mul(&generator, &scalar_2);
+        let point_double_g = double(&generator);
+        assert!(eq(&point_2g, &point_double_g), 1);
+
+        // Negation.
+        let point_minus_7g_calc = neg(&point_7g_calc);
+        assert!(G1_GENERATOR_MUL_BY_7_NEG_SERIALIZED_COMP == serialize<G1, FormatG1Compr>(&point_minus_7g_calc), 1);
+        assert!(G1_GENERATOR_MUL_BY_7_NEG_SERIALIZED_UNCOMP == serialize<G1, FormatG1Uncompr>(&point_minus_7g_calc), 1);
+
+        // Addition.
+        let scalar_9 = from_u64<Fr>(9);
+        let point_9g = scalar_mul(&generator, &scalar_9);
+        let point_2g_calc = add(&point_minus_7g_calc, &point_9g);
+        assert!(eq(&point_2g, &point_2g_calc), 1);
+
+        // Subtraction.
+        assert!(eq(&point_9g, &sub(&point_2g, &point_minus_7g_calc)), 1);
+    }
+
+    #[test_only]
+    use std::bn254_algebra;
+
+    #[test(fx = @std)]
+    fun test_bn254_fq_interop(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // The Grumpkin scalar field and the BN254 base field have the same modulus.
+        assert!(order<Fr>() == order<bn254_algebra::Fq>(), 1);
+        assert!(order<Fq>() == order<bn254_algebra::Fr>(), 1);
+
+        // A BN254 Fq element serializes identically to the Grumpkin scalar it casts to.
+        let bn254_val = rand_insecure<bn254_algebra::Fq>();
+        let grumpkin_val = std::option::extract(&mut downcast<bn254_algebra::Fq, Fr>(&bn254_val));
+        assert!(serialize<bn254_algebra::Fq, bn254_algebra::FormatFqLsb>(&bn254_val) == serialize<Fr, FormatFrLsb>(&grumpkin_val), 1);
+        assert!(serialize<bn254_algebra::Fq, bn254_algebra::FormatFqMsb>(&bn254_val) == serialize<Fr, FormatFrMsb>(&grumpkin_val), 1);
+
+        // Casting back yields the original element.
+        let bn254_val_2nd = upcast<Fr, bn254_algebra::Fq>(&grumpkin_val);
+        assert!(eq(&bn254_val, &bn254_val_2nd), 1);
+
+        // Casting commutes with the field operations.
+        let grumpkin_7 = from_u64<Fr>(7);
+        let bn254_7 = from_u64<bn254_algebra::Fq>(7);
+        let grumpkin_product = mul(&grumpkin_val, &grumpkin_7);
+        let bn254_product = mul(&bn254_val, &bn254_7);
+        assert!(eq(&bn254_product, &upcast<Fr, bn254_algebra::Fq>(&grumpkin_product)), 1);
+    }
+
+    #[test(fx = @std)]
+    fun test_structures_sharing_a_representation(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // `Fr` and `bn254_algebra::Fq` share an ark type, and so do `Fq` and `bn254_algebra::Fr`.
+        // Alternate between them within one session, so that each call resolves its type argument
+        // while the other structure is already cached.
+        let i = 0;
+        while (i < 3) {
+            let grumpkin_val = from_u64<Fr>(7);
+            let bn254_val = from_u64<bn254_algebra::Fq>(7);
+            assert!(eq(&from_u64<Fr>(49), &mul(&grumpkin_val, &grumpkin_val)), 1);
+            assert!(eq(&from_u64<bn254_algebra::Fq>(49), &mul(&bn254_val, &bn254_val)), 1);
+            assert!(eq(&bn254_val, &upcast<Fr, bn254_algebra::Fq>(&grumpkin_val)), 1);
+            assert!(eq(&grumpkin_val, &std::option::extract(&mut downcast<bn254_algebra::Fq, Fr>(&bn254_val))), 1);
+
+            // Scalar multiplication is only defined for each curve's own scalar field.
+            let grumpkin_point = scalar_mul(&one<G1>(), &from_u64<Fr>(2));
+            assert!(eq(&double(&one<G1>()), &grumpkin_point), 1);
+            let bn254_point = scalar_mul(&one<bn254_algebra::G1>(), &from_u64<bn254_algebra::Fr>(2));
+            assert!(eq(&double(&one<bn254_algebra::G1>()), &bn254_point), 1);
+            assert!(eq(&from_u64<Fq>(14), &add(&from_u64<Fq>(7), &from_u64<Fq>(7))), 1);
+            i = i + 1;
+        };
+    }
+
+    //
+    // (Tests end here.)
+    //
+}
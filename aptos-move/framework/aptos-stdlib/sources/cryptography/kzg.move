@@ -0,0 +1,153 @@
+/// This module implements verification of KZG polynomial commitment openings over the BN254 curve.
+///
+/// Given a commitment `C` to a polynomial `f`, an evaluation point `z`, a claimed value `v` and an opening proof `pi`,
+/// it checks that `f(z) = v` with the pairing equation `e(C - v * G1, G2) = e(pi, tau * G2 - z * G2)`,
+/// in a single native call.
+///
+/// `G1` and `G2` are the generators returned by `crypto_algebra::one()` for `bn254_algebra::G1` and
+/// `bn254_algebra::G2`. The SRS element `srs_g2` is `tau * G2`, the second element of the `G2` part of a powers-of-tau
+/// setup over these generators.
+///
+/// The commitment and the proof are 32-byte `G1` points and `srs_g2` is a 64-byte `G2` point, in the compressed
+/// encodings `FormatG1Compr` and `FormatG2Compr` of `aptos_std::bn254_algebra`. Points must be on the curve and in the
+/// prime-order subgroup.
+module aptos_std::kzg {
+    use std::error;
+    use std::features;
+
+    //
+    // Error codes
+    //
+
+    /// The commitment is not a valid encoding.
+    const E_MALFORMED_COMMITMENT: u64 = 1;
+
+    /// The opening proof is not a valid encoding.
+    const E_MALFORMED_PROOF: u64 = 2;
+
+    /// The SRS element is not a valid encoding.
+    const E_MALFORMED_SRS: u64 = 3;
+
+    /// The point or the value is not smaller than the order of the BN254 scalar field.
+    const E_SCALAR_NOT_CANONICAL: u64 = 4;
+
+    /// The native functions have not been rolled out yet.
+    const E_NATIVE_FUN_NOT_AVAILABLE: u64 = 5;
+
+    //
+    // Public functions
+    //
+
+    /// Verifies that `proof` opens `commitment` to `value` at `point`, where `point` and `value` are elements of the
+    /// BN254 scalar field. Returns false if the opening does not verify.
+    ///
+    /// Aborts with `error::invalid_argument(E_MALFORMED_COMMITMENT)` if `commitment` is not a valid `G1` point,
+    /// `error::invalid_argument(E_MALFORMED_PROOF)` if `proof` is not a valid `G1` point,
+    /// `error::invalid_argument(E_MALFORMED_SRS)` if `srs_g2` is not a valid `G2` point, and
+    /// `error::invalid_argument(E_SCALAR_NOT_CANONICAL)` if `point` or `value` is not smaller than the field order.
+    public fun kzg_verify_bn254(
+        commitment: vector<u8>,
+        point: u256,
+        value: u256,
+        proof: vector<u8>,
+        srs_g2: vector<u8>
+    ): bool {
+        assert!(features::kzg_bn254_enabled(), error::invalid_state(E_NATIVE_FUN_NOT_AVAILABLE));
+        kzg_verify_bn254_internal(commitment, point, value, proof, srs_g2)
+    }
+
+    //
+    // Native functions
+    //
+
+    native fun kzg_verify_bn254_internal(
+        commitment: vector<u8>,
+        point: u256,
+        value: u256,
+        proof: vector<u8>,
+        srs_g2: vector<u8>
+    ): bool;
+
+    //
+    // Testing
+    //
+
+    // A commitment to a degree-3 polynomial, opened at `POINT`. The setup was sampled for this test only.
+    #[test_only]
+    const COMMITMENT: vector<u8> = x"735f77a0f5f21c33413a6c5087dc40ac06c11a8ea06f8eeda4be1545cf42c703";
+    #[test_only]
+    const PROOF: vector<u8> = x"d0abaa5c7d23043d79a15090fca71ba5380c6ee1a7f2a00aaecea474fc1e2715";
+    #[test_only]
+    const SRS_G2: vector<u8> = x"1edc13148ea49b94296932a53ba3ab6a731c828515228ef3dbfb2c75ada7dc18e25231a31f8c876f76cf57219370ceb103c3cc9d79fe74caf78d3d770540ea2b";
+    #[test_only]
+    const POINT: u256 = 11252886426812627400748583046564132502879533403501633091968508060181128813224;
+    #[test_only]
+    const VALUE: u256 = 21722637242012032294877145027304799868392188868872003551410301757246809870600;
+    #[test_only]
+    /// An x-coordinate that has no point on the curve.
+    const G1_NOT_ON_CURVE: vector<u8> = x"0400000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    /// The order of the BN254 scalar field.
+    const R: u256 = 21888242871839275222246405745257275088548364400416034343698204186575808495617;
+
+    #[test_only]
+    fun enable_kzg(fx: &signer) {
+        features::change_feature_flags_for_testing(fx, vector[features::get_kzg_bn254_feature()], vector[]);
+    }
+
+    #[test(fx = @std)]
+    fun test_valid_opening(fx: signer) {
+        enable_kzg(&fx);
+        assert!(kzg_verify_bn254(COMMITMENT, POINT, VALUE, PROOF, SRS_G2), 1);
+    }
+
+    #[test(fx = @std)]
+    fun test_wrong_value(fx: signer) {
+        enable_kzg(&fx);
+        assert!(!kzg_verify_bn254(COMMITMENT, POINT, VALUE + 1, PROOF, SRS_G2), 1);
+    }
+
+    #[test(fx = @std)]
+    fun test_wrong_point(fx: signer) {
+        enable_kzg(&fx);
+        assert!(!kzg_verify_bn254(COMMITMENT, POINT + 1, VALUE, PROOF, SRS_G2), 1);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010001, location = Self)]
+    fun test_commitment_not_on_curve(fx: signer) {
+        enable_kzg(&fx);
+        kzg_verify_bn254(G1_NOT_ON_CURVE, POINT, VALUE, PROOF, SRS_G2);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010002, location = Self)]
+    fun test_proof_not_on_curve(fx: signer) {
+        enable_kzg(&fx);
+        kzg_verify_bn254(COMMITMENT, POINT, VALUE, G1_NOT_ON_CURVE, SRS_G2);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010003, location = Self)]
+    fun test_truncated_srs(fx: signer) {
+        enable_kzg(&fx);
+        let srs_g2 = SRS_G2;
+        std::vector::pop_back(&mut srs_g2);
+        kzg_verify_bn254(COMMITMENT, POINT, VALUE, PROOF, srs_g2);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010004, location = Self)]
+    fun test_value_not_canonical(fx: signer) {
+        enable_kzg(&fx);
+        // `R + VALUE` would be congruent to `VALUE`, if it were reduced.
+        kzg_verify_bn254(COMMITMENT, POINT, R + VALUE, PROOF, SRS_G2);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x030005, location = Self)]
+    fun test_native_fun_not_available(fx: signer) {
+        features::change_feature_flags_for_testing(&fx, vector[], vector[features::get_kzg_bn254_feature()]);
+        kzg_verify_bn254(COMMITMENT, POINT, VALUE, PROOF, SRS_G2);
+    }
+}
@@ -0,0 +1,11 @@
+spec aptos_std::kzg {
+    spec kzg_verify_bn254_internal(
+        commitment: vector<u8>,
+        point: u256,
+        value: u256,
+        proof: vector<u8>,
+        srs_g2: vector<u8>
+    ): bool {
+        pragma opaque;
+    }
+}
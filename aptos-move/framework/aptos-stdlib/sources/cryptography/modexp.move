@@ -0,0 +1,85 @@
+/// This module implements modular exponentiation of arbitrary-size unsigned integers,
+/// independently of the algebraic structures of `aptos_std::crypto_algebra`, e.g., for RSA signature verification.
+///
+/// It follows the semantics of the EVM `MODEXP` precompile ([EIP-198](https://eips.ethereum.org/EIPS/eip-198)):
+/// - Integers are big-endian byte arrays of any length. Leading zeros are allowed and do not change the value.
+/// - The result has as many bytes as the modulus, left-padded with zeros.
+/// - A zero modulus gives a zero result, so an empty modulus gives an empty result.
+///
+/// The gas cost builds on the EIP-198 formula: it grows quadratically with the length of the base or the modulus,
+/// whichever is longer, and linearly with the bit length of the exponent. Unlike EIP-198, every exponent bit also
+/// has a cost that does not depend on the operand lengths. It is charged before any computation.
+module aptos_std::modexp {
+    use std::error;
+    use std::features;
+
+    //
+    // Error codes
+    //
+
+    /// The native functions have not been rolled out yet.
+    const E_NATIVE_FUN_NOT_AVAILABLE: u64 = 1;
+
+    //
+    // Public functions
+    //
+
+    /// Returns `base^exp mod modulus`, encoded with the same length as `modulus`.
+    public fun modexp(base: vector<u8>, exp: vector<u8>, modulus: vector<u8>): vector<u8> {
+        assert!(features::modexp_enabled(), error::invalid_state(E_NATIVE_FUN_NOT_AVAILABLE));
+        modexp_internal(base, exp, modulus)
+    }
+
+    //
+    // Native functions
+    //
+
+    native fun modexp_internal(base: vector<u8>, exp: vector<u8>, modulus: vector<u8>): vector<u8>;
+
+    //
+    // Testing
+    //
+
+    #[test_only]
+    fun enable_modexp(fx: &signer) {
+        features::change_feature_flags_for_testing(fx, vector[features::get_modexp_feature()], vector[]);
+    }
+
+    #[test(fx = @std)]
+    fun test_eip198_vectors(fx: signer) {
+        enable_modexp(&fx);
+        // The modulus is the secp256k1 base field prime `p`, so `3^(p-1) mod p = 1` by Fermat's little theorem.
+        let exp = x"fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e";
+        let modulus = x"fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+        assert!(modexp(x"03", exp, modulus) == x"0000000000000000000000000000000000000000000000000000000000000001", 1);
+        // An empty base is zero.
+        assert!(modexp(x"", exp, modulus) == x"0000000000000000000000000000000000000000000000000000000000000000", 2);
+    }
+
+    #[test(fx = @std)]
+    fun test_leading_zeros(fx: signer) {
+        enable_modexp(&fx);
+        // `2^10 mod 1000 = 24`, padded to the length of the modulus.
+        assert!(modexp(x"0002", x"000a", x"0003e8") == x"000018", 1);
+        assert!(modexp(x"02", x"0a", x"03e8") == x"0018", 2);
+    }
+
+    #[test(fx = @std)]
+    fun test_edge_cases(fx: signer) {
+        enable_modexp(&fx);
+        // An empty exponent is zero.
+        assert!(modexp(x"05", x"", x"07") == x"01", 1);
+        // A zero modulus gives a zero result of the same length.
+        assert!(modexp(x"05", x"02", x"0000") == x"0000", 2);
+        assert!(modexp(x"05", x"02", x"") == x"", 3);
+        // A modulus of one always gives zero.
+        assert!(modexp(x"05", x"02", x"01") == x"00", 4);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x030001, location = Self)]
+    fun test_native_fun_not_available(fx: signer) {
+        features::change_feature_flags_for_testing(&fx, vector[], vector[features::get_modexp_feature()]);
+        modexp(x"02", x"0a", x"03e8");
+    }
+}
@@ -0,0 +1,5 @@
+spec aptos_std::modexp {
+    spec modexp_internal(base: vector<u8>, exp: vector<u8>, modulus: vector<u8>): vector<u8> {
+        pragma opaque;
+    }
+}
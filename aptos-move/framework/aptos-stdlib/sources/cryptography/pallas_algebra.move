@@ -0,0 +1,391 @@
+/// This module defines marker types, constants and test cases for working with the Pallas curve
+/// using the generic API defined in `crypto_algebra.move`.
+/// Pallas is one of the two Pasta curves used by Halo2 with the IPA commitment scheme.
+/// Pallas and Vesta form a cycle: the scalar field of one curve is the base field of the other,
+/// so `pallas_algebra::Fr` is the same field as `vesta_algebra::Fq`,
+/// and `pallas_algebra::Fq` is the same field as `vesta_algebra::Fr`.
+///
+/// Curve information:
+/// * Base field: q =
+///   28948022309329048855892746252171976963363056481941560715954676764349967630337
+/// * Scalar field: r =
+///   28948022309329048855892746252171976963363056481941647379679742748393362948097
+/// * Curve equation: y^2 = x^3 + 5
+/// * Cofactor: 1
+///
+/// Currently-supported Pallas structures include `Fr`, `Fq` and `G1`,
+/// along with their widely-used serialization formats.
+///
+/// The curve is not pairing-friendly, so no pairing is defined for it.
+module aptos_std::pallas_algebra {
+    //
+    // Marker types + serialization formats begin.
+    //
+
+    /// The finite field $F_r$ that can be used as the scalar field associated with the group `G1`.
+    /// It is the same field as `vesta_algebra::Fq`.
+    struct Fr {}
+
+    /// A serialization format for `Fr` elements,
+    /// where an element is represented by a byte array `b[]` of size 32 with the least significant byte (LSB) coming first.
+    ///
+    /// NOTE: other implementation(s) using this format: ark-pallas-0.4.0, pasta_curves-0.5.1.
+    struct FormatFrLsb {}
+
+    /// A serialization scheme for `Fr` elements,
+    /// where an element is represented by a byte array `b[]` of size 32 with the most significant byte (MSB) coming first.
+    ///
+    /// NOTE: other implementation(s) using this format: ark-pallas-0.4.0.
+    struct FormatFrMsb {}
+
+    /// The finite field $F_q$ that can be used as the base field of `G1`.
+    /// It is the same field as `vesta_algebra::Fr`.
+    struct Fq {}
+
+    /// A serialization format for `Fq` elements,
+    /// where an element is represented by a byte array `b[]` of size 32 with the least significant byte (LSB) coming first.
+    ///
+    /// NOTE: other implementation(s) using this format: ark-pallas-0.4.0, pasta_curves-0.5.1.
+    struct FormatFqLsb {}
+
+    /// A serialization scheme for `Fq` elements,
+    /// where an element is represented by a byte array `b[]` of size 32 with the most significant byte (MSB) coming first.
+    ///
+    /// NOTE: other implementation(s) using this format: ark-pallas-0.4.0.
+    struct FormatFqMsb {}
+
+    /// The group constructed by the points on the Pallas curve $E(F_q): y^2 = x^3 + 5$ and the point at infinity,
+    /// under the elliptic curve point addition.
+    /// It has a prime order $r$ equal to 0x40000000000000000000000000000000224698fc0994a8dd8c46eb2100000001 (the cofactor is 1),
+    /// so `Fr` is the associated scalar field.
+    /// The generator is $(-1, 2)$.
+    struct G1 {}
+
+    /// An uncompressed serialization scheme for `G1` elements.
+    ///
+    /// Below is the serialization procedure that takes a `G1` element `p` and outputs a byte array of size 64.
+    /// 1. If `p` is the point at infinity, return 64 zero bytes.
+    /// 1. Let `(x,y)` be the coordinates of `p`.
+    /// 1. Serialize `x` and `y` into `b_x[]` and `b_y[]` respectively using `FormatFqLsb`.
+    /// 1. Return `b_x[] || b_y[]`.
+    ///
+    /// Below is the deserialization procedure that takes a byte array `b[]` and outputs either a `G1` element or none.
+    /// 1. If the size of `b[]` is not 64, return none.
+    /// 1. If `b[]` is all zeros, return the point at infinity.
+    /// 1. Deserialize `b[0..32]` to `x` using `FormatFqLsb`. If `x` is none, return none.
+    /// 1. Deserialize `b[32..64]` to `y` using `FormatFqLsb`. If `y` is none, return none.
+    /// 1. Check if `(x,y)` is on curve `E`. If not, return none.
+    /// 1. Return `(x,y)`.
+    struct FormatG1Uncompr {}
+
+    /// A compressed serialization scheme for `G1` elements, as used by Halo2 transcripts.
+    ///
+    /// Below is the serialization procedure that takes a `G1` element `p` and outputs a byte array of size 32.
+    /// 1. If `p` is the point at infinity, return 32 zero bytes.
+    /// 1. Let `(x,y)` be the coordinates of `p`.
+    /// 1. Serialize `x` into `b[]` using `FormatFqLsb`.
+    /// 1. If `y` is odd, set the sign bit: `b[31] := b[31] | 0b1000_0000`.
+    /// 1. Return `b[]`.
+    ///
+    /// Below is the deserialization procedure that takes a byte array `b[]` and outputs either a `G1` element or none.
+    /// 1. If the size of `b[]` is not 32, return none.
+    /// 1. Compute the sign flag as `b[31] & 0b1000_0000 != 0`.
+    /// 1. Deserialize `[b[0], b[1], ..., b[31] & 0b0111_1111]` to `x` using `FormatFqLsb`. If `x` is none, return none.
+    /// 1. If `x` is 0 and the sign flag is not set, return the point at infinity.
+    /// 1. Solve the curve equation with `x` for `y`. If no such `y` exists, return none.
+    /// 1. Let `y'` be whichever of `y` and `-y` is odd if the sign flag is set, or even otherwise.
+    /// 1. Return `(x,y')`.
+    ///
+    /// NOTE: other implementation(s) using this format: pasta_curves-0.5.1.
+    struct FormatG1Compr {}
+
+    // Tests begin.
+
+    #[test_only]
+    fun rand_vector<S>(num: u64): vector<Element<S>> {
+        let elements = vector[];
+        while (num > 0) {
+            std::vector::push_back(&mut elements, rand_insecure<S>());
+            num = num - 1;
+        };
+        elements
+    }
+
+    #[test_only]
+    use aptos_std::crypto_algebra::{Element, zero, one, from_u64, eq, deserialize, serialize, neg, add, sub, mul, div, inv, rand_insecure, sqr, pow_u64, order, scalar_mul, multi_scalar_mul, double, enable_cryptography_algebra_natives};
+
+    #[test_only]
+    const R_SERIALIZED: vector<u8> = x"0100000021eb468cdda89409fc98462200000000000000000000000000000040";
+    #[test_only]
+    const FR_VAL_0_SERIALIZED_LSB: vector<u8> = x"0000000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FR_VAL_1_SERIALIZED_LSB: vector<u8> = x"0100000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FR_VAL_7_SERIALIZED_LSB: vector<u8> = x"0700000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FR_VAL_7_SERIALIZED_MSB: vector<u8> = x"0000000000000000000000000000000000000000000000000000000000000007";
+    #[test_only]
+    const FR_VAL_7_NEG_SERIALIZED_LSB: vector<u8> = x"faffffff20eb468cdda89409fc98462200000000000000000000000000000040";
+
+    #[test(fx = @std)]
+    fun test_fr(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // Constants.
+        assert!(R_SERIALIZED == order<Fr>(), 1);
+
+        // Serialization/deserialization.
+        let val_0 = zero<Fr>();
+        let val_1 = one<Fr>();
+        assert!(FR_VAL_0_SERIALIZED_LSB == serialize<Fr, FormatFrLsb>(&val_0), 1);
+        assert!(FR_VAL_1_SERIALIZED_LSB == serialize<Fr, FormatFrLsb>(&val_1), 1);
+        let val_7 = from_u64<Fr>(7);
+        let val_7_2nd = std::option::extract(&mut deserialize<Fr, FormatFrLsb>(&FR_VAL_7_SERIALIZED_LSB));
+        let val_7_3rd = std::option::extract(&mut deserialize<Fr, FormatFrMsb>(&FR_VAL_7_SERIALIZED_MSB));
+        assert!(eq(&val_7, &val_7_2nd), 1);
+        assert!(eq(&val_7, &val_7_3rd), 1);
+        assert!(FR_VAL_7_SERIALIZED_LSB == serialize<Fr, FormatFrLsb>(&val_7), 1);
+        assert!(FR_VAL_7_SERIALIZED_MSB == serialize<Fr, FormatFrMsb>(&val_7), 1);
+
+        // Deserialization should fail if given a byte array of right size but the value is not a member.
+        assert!(std::option::is_none(&deserialize<Fr, FormatFrLsb>(&x"0100000021eb468cdda89409fc98462200000000000000000000000000000040")), 1);
+        assert!(std::option::is_none(&deserialize<Fr, FormatFrMsb>(&x"40000000000000000000000000000000224698fc0994a8dd8c46eb2100000001")), 1);
+
+        // Deserialization should fail if given a byte array of wrong size.
+        assert!(std::option::is_none(&deserialize<Fr, FormatFrLsb>(&x"070000000000000000000000000000000000000000000000000000000000000000")), 1);
+        assert!(std::option::is_none(&deserialize<Fr, FormatFrMsb>(&x"000000000000000000000000000000000000000000000000000000000000000007")), 1);
+        assert!(std::option::is_none(&deserialize<Fr, FormatFrLsb>(&x"ffff")), 1);
+        assert!(std::option::is_none(&deserialize<Fr, FormatFrMsb>(&x"ffff")), 1);
+
+        // Negation.
+        let val_minus_7 = neg(&val_7);
+        assert!(FR_VAL_7_NEG_SERIALIZED_LSB == serialize<Fr, FormatFrLsb>(&val_minus_7), 1);
+
+        // Addition.
+        let val_9 = from_u64<Fr>(9);
+        let val_2 = from_u64<Fr>(2);
+        assert!(eq(&val_2, &add(&val_minus_7, &val_9)), 1);
+
+        // Subtraction.
+        assert!(eq(&val_9, &sub(&val_2, &val_minus_7)), 1);
+
+        // Multiplication.
+        let val_63 = from_u64<Fr>(63);
+        assert!(eq(&val_63, &mul(&val_7, &val_9)), 1);
+
+        // division.
+        let val_0 = from_u64<Fr>(0);
+        assert!(eq(&val_7, &std::option::extract(&mut div(&val_63, &val_9))), 1);
+        assert!(std::option::is_none(&div(&val_63, &val_0)), 1);
+
+        // Inversion.
+        assert!(eq(&val_1, &mul(&val_7, &std::option::extract(&mut inv(&val_7)))), 1);
+        assert!(std::option::is_none(&inv(&val_0)), 1);
+
+        // Squaring.
+        let val_x = rand_insecure<Fr>();
+        assert!(eq(&mul(&val_x, &val_x), &sqr(&val_x)), 1);
+    }
+
+    #[test(fx = @std)]
+    fun test_pow_u64(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        let x = rand_insecure<Fr>();
+        assert!(eq(&one<Fr>(), &pow_u64(&x, 0)), 1);
+        assert!(eq(&x, &pow_u64(&x, 1)), 1);
+        assert!(eq(&mul(&x, &sqr(&x)), &pow_u64(&x, 3)), 1);
+        assert!(eq(&from_u64<Fq>(1024), &pow_u64(&from_u64<Fq>(2), 10)), 1);
+        let y = rand_insecure<Fq>();
+        assert!(eq(&pow_u64(&y, 18446744073709551615), &mul(&pow_u64(&y, 18446744073709551614), &y)), 1);
+    }
+
+    #[test_only]
+    const Q_SERIALIZED: vector<u8> = x"01000000ed302d991bf94c09fc98462200000000000000000000000000000040";
+    #[test_only]
+    const FQ_VAL_0_SERIALIZED_LSB: vector<u8> = x"0000000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FQ_VAL_1_SERIALIZED_LSB: vector<u8> = x"0100000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FQ_VAL_7_SERIALIZED_LSB: vector<u8> = x"0700000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const FQ_VAL_7_SERIALIZED_MSB: vector<u8> = x"0000000000000000000000000000000000000000000000000000000000000007";
+    #[test_only]
+    const FQ_VAL_7_NEG_SERIALIZED_LSB: vector<u8> = x"faffffffec302d991bf94c09fc98462200000000000000000000000000000040";
+
+    #[test(fx = @std)]
+    fun test_fq(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // Constants.
+        assert!(Q_SERIALIZED == order<Fq>(), 1);
+
+        // Serialization/deserialization.
+        let val_0 = zero<Fq>();
+        let val_1 = one<Fq>();
+        assert!(FQ_VAL_0_SERIALIZED_LSB == serialize<Fq, FormatFqLsb>(&val_0), 1);
+        assert!(FQ_VAL_1_SERIALIZED_LSB == serialize<Fq, FormatFqLsb>(&val_1), 1);
+        let val_7 = from_u64<Fq>(7);
+        let val_7_2nd = std::option::extract(&mut deserialize<Fq, FormatFqLsb>(&FQ_VAL_7_SERIALIZED_LSB));
+        let val_7_3rd = std::option::extract(&mut deserialize<Fq, FormatFqMsb>(&FQ_VAL_7_SERIALIZED_MSB));
+        assert!(eq(&val_7, &val_7_2nd), 1);
+        assert!(eq(&val_7, &val_7_3rd), 1);
+        assert!(FQ_VAL_7_SERIALIZED_LSB == serialize<Fq, FormatFqLsb>(&val_7), 1);
+        assert!(FQ_VAL_7_SERIALIZED_MSB == serialize<Fq, FormatFqMsb>(&val_7), 1);
+
+        // Deserialization should fail if given a byte array of right size but the value is not a member.
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqLsb>(&x"01000000ed302d991bf94c09fc98462200000000000000000000000000000040")), 1);
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqMsb>(&x"40000000000000000000000000000000224698fc094cf91b992d30ed00000001")), 1);
+
+        // Deserialization should fail if given a byte array of wrong size.
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqLsb>(&x"070000000000000000000000000000000000000000000000000000000000000000")), 1);
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqMsb>(&x"000000000000000000000000000000000000000000000000000000000000000007")), 1);
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqLsb>(&x"ffff")), 1);
+        assert!(std::option::is_none(&deserialize<Fq, FormatFqMsb>(&x"ffff")), 1);
+
+        // Negation.
+        let val_minus_7 = neg(&val_7);
+        assert!(FQ_VAL_7_NEG_SERIALIZED_LSB == serialize<Fq, FormatFqLsb>(&val_minus_7), 1);
+
+        // Addition.
+        let val_9 = from_u64<Fq>(9);
+        let val_2 = from_u64<Fq>(2);
+        assert!(eq(&val_2, &add(&val_minus_7, &val_9)), 1);
+
+        // Subtraction.
+        assert!(eq(&val_9, &sub(&val_2, &val_minus_7)), 1);
+
+        // Multiplication.
+        let val_63 = from_u64<Fq>(63);
+        assert!(eq(&val_63, &mul(&val_7, &val_9)), 1);
+
+        // division.
+        let val_0 = from_u64<Fq>(0);
+        assert!(eq(&val_7, &std::option::extract(&mut div(&val_63, &val_9))), 1);
+        assert!(std::option::is_none(&div(&val_63, &val_0)), 1);
+
+        // Inversion.
+        assert!(eq(&val_1, &mul(&val_7, &std::option::extract(&mut inv(&val_7)))), 1);
+        assert!(std::option::is_none(&inv(&val_0)), 1);
+
+        // Squaring.
+        let val_x = rand_insecure<Fq>();
+        assert!(eq(&mul(&val_x, &val_x), &sqr(&val_x)), 1);
+    }
+
+    #[test_only]
+    const G1_INF_SERIALIZED_COMP: vector<u8> = x"0000000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const G1_INF_SERIALIZED_UNCOMP: vector<u8> = x"00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const G1_GENERATOR_SERIALIZED_COMP: vector<u8> = x"00000000ed302d991bf94c09fc98462200000000000000000000000000000040";
+    #[test_only]
+    const G1_GENERATOR_SERIALIZED_UNCOMP: vector<u8> = x"00000000ed302d991bf94c09fc984622000000000000000000000000000000400200000000000000000000000000000000000000000000000000000000000000";
+    #[test_only]
+    const G1_GENERATOR_MUL_BY_7_SERIALIZED_COMP: vector<u8> = x"998b9d02ab10540a55a6ec55855c743ee3d8f8b10232bc22cc00abb11438a499";
+    #[test_only]
+    const G1_GENERATOR_MUL_BY_7_SERIALIZED_UNCOMP: vector<u8> = x"998b9d02ab10540a55a6ec55855c743ee3d8f8b10232bc22cc00abb11438a419ad2210c3341495b44e5cd88eb08b6e7bade02b68c34185741125cf15cbf48823";
+    #[test_only]
+    const G1_GENERATOR_MUL_BY_7_NEG_SERIALIZED_COMP: vector<u8> = x"998b9d02ab10540a55a6ec55855c743ee3d8f8b10232bc22cc00abb11438a419";
+    #[test_only]
+    const G1_GENERATOR_MUL_BY_7_NEG_SERIALIZED_UNCOMP: vector<u8> = x"998b9d02ab10540a55a6ec55855c743ee3d8f8b10232bc22cc00abb11438a41954ddef3cb81c98e4cc9c747a4b0dd8a6521fd4973cbe7a8beeda30ea340b771c";
+
+    #[test(fx = @std)]
+    fun test_g1(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // Constants.
+        assert!(R_SERIALIZED == order<G1>(), 1);
+        let point_at_infinity = zero<G1>();
+        let generator = one<G1>();
+
+        // Serialization/deserialization.
+        assert!(G1_GENERATOR_SERIALIZED_UNCOMP == serialize<G1, FormatG1Uncompr>(&generator), 1);
+        assert!(G1_GENERATOR_SERIALIZED_COMP == serialize<G1, FormatG1Compr>(&generator), 1);
+        let generator_from_comp = std::option::extract(&mut deserialize<G1, FormatG1Compr>(&G1_GENERATOR_SERIALIZED_COMP));
+        let generator_from_uncomp = std::option::extract(&mut deserialize<G1, FormatG1Uncompr>(&G1_GENERATOR_SERIALIZED_UNCOMP));
+        assert!(eq(&generator, &generator_from_comp), 1);
+        assert!(eq(&generator, &generator_from_uncomp), 1);
+
+        assert!(G1_INF_SERIALIZED_UNCOMP == serialize<G1, FormatG1Uncompr>(&point_at_infinity), 1);
+        assert!(G1_INF_SERIALIZED_COMP == serialize<G1, FormatG1Compr>(&point_at_infinity), 1);
+        let inf_from_uncomp = std::option::extract(&mut deserialize<G1, FormatG1Uncompr>(&G1_INF_SERIALIZED_UNCOMP));
+        let inf_from_comp = std::option::extract(&mut deserialize<G1, FormatG1Compr>(&G1_INF_SERIALIZED_COMP));
+        assert!(eq(&point_at_infinity, &inf_from_comp), 1);
+        assert!(eq(&point_at_infinity, &inf_from_uncomp), 1);
+
+        let point_7g_from_uncomp = std::option::extract(&mut deserialize<G1, FormatG1Uncompr>(&G1_GENERATOR_MUL_BY_7_SERIALIZED_UNCOMP));
+        let point_7g_from_comp = std::option::extract(&mut deserialize<G1, FormatG1Compr>(&G1_GENERATOR_MUL_BY_7_SERIALIZED_COMP));
+        assert!(eq(&point_7g_from_comp, &point_7g_from_uncomp), 1);
+
+        // Round trips.
+        let point_x = rand_insecure<G1>();
+        let point_x_from_comp = std::option::extract(&mut deserialize<G1, FormatG1Compr>(&serialize<G1, FormatG1Compr>(&point_x)));
+        let point_x_from_uncomp = std::option::extract(&mut deserialize<G1, FormatG1Uncompr>(&serialize<G1, FormatG1Uncompr>(&point_x)));
+        assert!(eq(&point_x, &point_x_from_comp), 1);
+        assert!(eq(&point_x, &point_x_from_uncomp), 1);
+
+        // Deserialization should fail if given a valid point in (Fq,Fq) but not on the curve.
+        assert!(std::option::is_none(&deserialize<G1, FormatG1Uncompr>(&x"00000000ed302d991bf94c09fc984622000000000000000000000000000000400300000000000000000000000000000000000000000000000000000000000000")), 1);
+        assert!(std::option::is_none(&deserialize<G1, FormatG1Compr>(&x"0200000000000000000000000000000000000000000000000000000000000000")), 1);
+
+        // Deserialization should fail if given an invalid point (x not in Fq).
+        assert!(std::option::is_none(&deserialize<G1, FormatG1Compr>(&x"01000000ed302d991bf94c09fc98462200000000000000000000000000000040")), 1);
+        assert!(std::option::is_none(&deserialize<G1, FormatG1Uncompr>(&x"01000000ed302d991bf94c09fc984622000000000000000000000000000000400200000000000000000000000000000000000000000000000000000000000000")), 1);
+
+        // Deserialization should fail if given a byte array of wrong size.
+        assert!(std::option::is_none(&deserialize<G1, FormatG1Uncompr>(&x"00000000ed302d991bf94c09fc98462200000000000000000000000000000040020000000000000000000000000000000000000000000000000000000000000000")), 1);
+        assert!(std::option::is_none(&deserialize<G1, FormatG1Compr>(&x"00000000ed302d991bf94c09fc9846220000000000000000000000000000004000")), 1);
+
+        // Scalar multiplication.
+        let scalar_7 = from_u64<Fr>(7);
+        let point_7g_calc = scalar_mul(&generator, &scalar_7);
+        assert!(eq(&point_7g_calc, &point_7g_from_comp), 1);
+        assert!(G1_GENERATOR_MUL_BY_7_SERIALIZED_UNCOMP == serialize<G1, FormatG1Uncompr>(&point_7g_calc), 1);
+        assert!(G1_GENERATOR_MUL_BY_7_SERIALIZED_COMP == serialize<G1, FormatG1Compr>(&point_7g_calc), 1);
+
+        // Multi-scalar multiplication.
+        let num_entries = 1;
+        while (num_entries < 10) {
+            let scalars = rand_vector<Fr>(num_entries);
+            let elements = rand_vector<G1>(num_entries);
+
+            let expected = zero<G1>();
+            let i = 0;
+            while (i < num_entries) {
+                let element = std::vector::borrow(&elements, i);
+                let scalar = std::vector::borrow(&scalars, i);
+                expected = add(&expected, &scalar_mul(element, scalar));
+                i = i + 1;
+            };
+
+            let actual = multi_scalar_mul(&elements, &scalars);
+            assert!(eq(&expected, &actual), 1);
+
+            num_entries = num_entries + 1;
+        };
+
+        // Doubling.
+        let scalar_2 = from_u64<Fr>(2);
+        let point_2g = scalar_mul(&generator, &scalar_2);
+        let point_double_g = double(&generator);
+        assert!(eq(&point_2g, &point_double_g), 1);
+
+        // Negation.
+        let point_minus_7g_calc = neg(&point_7g_calc);
+        assert!(G1_GENERATOR_MUL_BY_7_NEG_SERIALIZED_COMP == serialize<G1, FormatG1Compr>(&point_minus_7g_calc), 1);
+        assert!(G1_GENERATOR_MUL_BY_7_NEG_SERIALIZED_UNCOMP == serialize<G1, FormatG1Uncompr>(&point_minus_7g_calc), 1);
+
+        // Addition.
+        let scalar_9 = from_u64<Fr>(9);
+        let point_9g = scalar_mul(&generator, &scalar_9);
+        let point_2g_calc = add(&point_minus_7g_calc, &point_9g);
+        assert!(eq(&point_2g, &point_2g_calc), 1);
+
+        // Subtraction.
+        assert!(eq(&point_9g, &sub(&point_2g, &point_minus_7g_calc)), 1);
+    }
+
+    //
+    // (Tests end here.)
+    //
+}
@@ -17,6 +17,9 @@ module aptos_std::aptos_hash {
     /// A newly-added native function is not yet enabled.
     const E_NATIVE_FUN_NOT_AVAILABLE: u64 = 1;
 
+    /// The SHA2-256 compression function expects an 8-word state and a 16-word block.
+    const E_SHA256_COMPRESS_INVALID_LENGTH: u64 = 2;
+
     //
     // Functions
     //
@@ -74,6 +77,41 @@ module aptos_std::aptos_hash {
         blake2b_256_internal(bytes)
     }
 
+    /// Returns the Keccak-256 hash of `bytes`, reduced into the BN254 scalar field.
+    ///
+    /// The 32-byte digest is read as a big-endian integer and reduced modulo the BN254 scalar field order
+    /// r = 21888242871839275222246405745257275088548364400416034343698204186575808495617,
+    /// the same as `uint256(keccak256(bytes)) % r` in Solidity.
+    /// The reduction is a plain modulo rather than rejection sampling, so the output is slightly biased
+    /// towards small values, but is always a canonical field element in `[0, r)`.
+    public fun keccak_to_bn254_fr(bytes: vector<u8>): u256 {
+        if(!features::keccak_to_bn254_fr_enabled()) {
+            abort(std::error::invalid_state(E_NATIVE_FUN_NOT_AVAILABLE))
+        };
+
+        keccak_to_bn254_fr_internal(bytes)
+    }
+
+    /// Applies the SHA2-256 compression function to a single 512-bit `block`, starting from the
+    /// chaining value `state`, and returns the next chaining value (FIPS 180-4, section 6.2.2).
+    ///
+    /// `state` must hold 8 words and `block` must hold 16 big-endian words. The message schedule is
+    /// computed inside the compression function, but no padding is applied, so the caller is
+    /// responsible for padding the message and for chaining the output of each block into the next.
+    /// Starting from the SHA2-256 initial hash value and compressing every padded block in order
+    /// yields the SHA2-256 digest.
+    public fun sha256_compress(state: vector<u32>, block: vector<u32>): vector<u32> {
+        if(!features::sha256_compress_enabled()) {
+            abort(std::error::invalid_state(E_NATIVE_FUN_NOT_AVAILABLE))
+        };
+        assert!(
+            std::vector::length(&state) == 8 && std::vector::length(&block) == 16,
+            std::error::invalid_argument(E_SHA256_COMPRESS_INVALID_LENGTH)
+        );
+
+        sha256_compress_internal(state, block)
+    }
+
     //
     // Private native functions
     //
@@ -94,6 +132,12 @@ module aptos_std::aptos_hash {
     /// Returns the BLAKE2B-256 hash of `bytes`.
     native fun blake2b_256_internal(bytes: vector<u8>): vector<u8>;
 
+    /// Returns the Keccak-256 hash of `bytes`, reduced into the BN254 scalar field.
+    native fun keccak_to_bn254_fr_internal(bytes: vector<u8>): u256;
+
+    /// Applies the SHA2-256 compression function to `block`, starting from `state`.
+    native fun sha256_compress_internal(state: vector<u32>, block: vector<u32>): vector<u32>;
+
     //
     // Testing
     //
@@ -250,4 +294,102 @@ module aptos_std::aptos_hash {
             i = i + 1;
         };
     }
+
+    #[test(fx = @aptos_std)]
+    #[expected_failure(abort_code = 196609, location = Self)]
+    fun keccak_to_bn254_fr_aborts(fx: signer) {
+        // We disable the feature to make sure the `keccak_to_bn254_fr` call aborts
+        features::change_feature_flags_for_testing(&fx, vector[], vector[features::get_keccak_to_bn254_fr_feature()]);
+
+        keccak_to_bn254_fr(b"This will abort");
+    }
+
+    #[test(fx = @aptos_std)]
+    fun keccak_to_bn254_fr_test(fx: signer) {
+        // We need to enable the feature in order for the native call to be allowed.
+        features::change_feature_flags_for_testing(&fx, vector[features::get_keccak_to_bn254_fr_feature()], vector[]);
+        let inputs = vector[
+        b"testing",
+        b"",
+        ];
+
+        // From the following Solidity function, where `r` is the BN254 scalar field order:
+        // ```
+        //   function hashToField(bytes memory input) public pure returns (uint256) {
+        //       return uint256(keccak256(input)) % r;
+        //   }
+        // ```
+        let outputs = vector[
+        21122037795544729432194802240975621904720818550230897449199583927781809789697,
+        1924180730567573949438414972962865885128629851683618892617351438379423999084,
+        ];
+
+        let i = 0;
+        while (i < std::vector::length(&inputs)) {
+            let input = *std::vector::borrow(&inputs, i);
+            let hash_expected = *std::vector::borrow(&outputs, i);
+            let hash = keccak_to_bn254_fr(input);
+
+            assert!(hash_expected == hash, 1);
+            assert!(hash < 21888242871839275222246405745257275088548364400416034343698204186575808495617, 1);
+
+            i = i + 1;
+        };
+    }
+
+    #[test(fx = @aptos_std)]
+    #[expected_failure(abort_code = 196609, location = Self)]
+    fun sha256_compress_aborts(fx: signer) {
+        // We disable the feature to make sure the `sha256_compress` call aborts
+        features::change_feature_flags_for_testing(&fx, vector[], vector[features::get_sha256_compress_feature()]);
+
+        sha256_compress(vector[0, 0, 0, 0, 0, 0, 0, 0], vector[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test(fx = @aptos_std)]
+    #[expected_failure(abort_code = 0x010002, location = Self)]
+    fun sha256_compress_invalid_length(fx: signer) {
+        features::change_feature_flags_for_testing(&fx, vector[features::get_sha256_compress_feature()], vector[]);
+
+        sha256_compress(vector[0, 0, 0, 0, 0, 0, 0], vector[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test(fx = @aptos_std)]
+    fun sha256_compress_test(fx: signer) {
+        // We need to enable the feature in order for the native call to be allowed.
+        features::change_feature_flags_for_testing(&fx, vector[features::get_sha256_compress_feature()], vector[]);
+
+        // The SHA2-256 initial hash value.
+        let h0 = vector[
+            0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+        ];
+
+        // One-block message "abc", from the FIPS 180-4 example values.
+        let block = vector[
+            0x61626380, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x18,
+        ];
+        let digest = sha256_compress(h0, block);
+        assert!(digest == vector[
+            0xba7816bf, 0x8f01cfea, 0x414140de, 0x5dae2223, 0xb00361a3, 0x96177a9c, 0xb410ff61, 0xf20015ad,
+        ], 1);
+
+        // Two-block message "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq", from the FIPS 180-4
+        // example values, including the intermediate hash value after the first block.
+        let block_1 = vector[
+            0x61626364, 0x62636465, 0x63646566, 0x64656667, 0x65666768, 0x66676869, 0x6768696a, 0x68696a6b,
+            0x696a6b6c, 0x6a6b6c6d, 0x6b6c6d6e, 0x6c6d6e6f, 0x6d6e6f70, 0x6e6f7071, 0x80000000, 0,
+        ];
+        let block_2 = vector[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x1c0,
+        ];
+        let h1 = sha256_compress(h0, block_1);
+        assert!(h1 == vector[
+            0x85e655d6, 0x417a1795, 0x3363376a, 0x624cde5c, 0x76e09589, 0xcac5f811, 0xcc4b32c1, 0xf20e533a,
+        ], 2);
+        let digest = sha256_compress(h1, block_2);
+        assert!(digest == vector[
+            0x248d6a61, 0xd20638b8, 0xe5c02693, 0x0c3e6039, 0xa33ce459, 0x64ff2167, 0xf6ecedd4, 0x19db06c1,
+        ], 3);
+        assert!(std::hash::sha2_256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq") == x"248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1", 4);
+    }
 }
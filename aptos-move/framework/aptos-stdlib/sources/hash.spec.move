@@ -27,6 +27,9 @@ spec aptos_std::aptos_hash {
         fun spec_blake2b_256_internal(bytes: vector<u8>): vector<u8>;
         axiom forall b1: vector<u8>, b2: vector<u8>:
             (spec_blake2b_256_internal(b1) == spec_blake2b_256_internal(b2) ==> b1 == b2);
+
+        /// `spec_keccak_to_bn254_fr_internal` is not assumed to be injective, as the digest is reduced.
+        fun spec_keccak_to_bn254_fr_internal(bytes: vector<u8>): u256;
     }
 
     spec sip_hash(bytes: vector<u8>): u64 {
@@ -94,4 +97,29 @@ spec aptos_std::aptos_hash {
         ensures result == spec_blake2b_256_internal(bytes);
     }
 
+    spec keccak_to_bn254_fr_internal(bytes: vector<u8>): u256 {
+        pragma opaque;
+        aborts_if false;
+        ensures result == spec_keccak_to_bn254_fr_internal(bytes);
+    }
+
+    spec keccak_to_bn254_fr(bytes: vector<u8>): u256 {
+        pragma opaque;
+        aborts_if !features::spec_is_enabled(features::KECCAK_TO_BN254_FR_NATIVE);
+        ensures result == spec_keccak_to_bn254_fr_internal(bytes);
+    }
+
+    spec sha256_compress_internal(state: vector<u32>, block: vector<u32>): vector<u32> {
+        pragma opaque;
+        aborts_if [abstract] len(state) != 8 || len(block) != 16;
+        ensures [abstract] len(result) == 8;
+    }
+
+    spec sha256_compress(state: vector<u32>, block: vector<u32>): vector<u32> {
+        pragma opaque;
+        aborts_if !features::spec_is_enabled(features::SHA256_COMPRESS_NATIVE);
+        aborts_if len(state) != 8 || len(block) != 16;
+        ensures len(result) == 8;
+    }
+
 }
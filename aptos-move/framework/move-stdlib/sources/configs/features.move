@@ -530,6 +530,182 @@ module std::features {
         is_enabled(DISPATCHABLE_FUNGIBLE_ASSET)
     }
 
+    /// Whether the generic algebra implementation for secp256k1 and secp256r1 operations are enabled.
+    ///
+    /// Lifetime: transient
+    const SECP256K1_SECP256R1_STRUCTURES: u64 = 64;
+
+    public fun get_secp256k1_secp256r1_structures_feature(): u64 { SECP256K1_SECP256R1_STRUCTURES }
+
+    public fun secp256k1_secp256r1_structures_enabled(): bool acquires Features {
+        is_enabled(SECP256K1_SECP256R1_STRUCTURES)
+    }
+
+    /// Whether the generic algebra implementation for Grumpkin operations are enabled.
+    ///
+    /// Lifetime: transient
+    const GRUMPKIN_STRUCTURES: u64 = 65;
+
+    public fun get_grumpkin_structures_feature(): u64 { GRUMPKIN_STRUCTURES }
+
+    public fun grumpkin_structures_enabled(): bool acquires Features {
+        is_enabled(GRUMPKIN_STRUCTURES)
+    }
+
+    /// Whether the generic algebra implementation for Pallas and Vesta operations are enabled.
+    ///
+    /// Lifetime: transient
+    const PASTA_STRUCTURES: u64 = 66;
+
+    public fun get_pasta_structures_feature(): u64 { PASTA_STRUCTURES }
+
+    public fun pasta_structures_enabled(): bool acquires Features {
+        is_enabled(PASTA_STRUCTURES)
+    }
+
+    /// Whether the new Keccak-256 to BN254 scalar hash function native is enabled.
+    /// This is needed because of the introduction of new native function(s).
+    /// Lifetime: transient
+    const KECCAK_TO_BN254_FR_NATIVE: u64 = 67;
+
+    public fun get_keccak_to_bn254_fr_feature(): u64 { KECCAK_TO_BN254_FR_NATIVE }
+
+    public fun keccak_to_bn254_fr_enabled(): bool acquires Features {
+        is_enabled(KECCAK_TO_BN254_FR_NATIVE)
+    }
+
+    /// Whether the Groth16 proof verification native over BN254 is enabled.
+    /// This is needed because of the introduction of new native function(s).
+    /// Lifetime: transient
+    const GROTH16_BN254_NATIVE: u64 = 68;
+
+    public fun get_groth16_bn254_feature(): u64 { GROTH16_BN254_NATIVE }
+
+    public fun groth16_bn254_enabled(): bool acquires Features {
+        is_enabled(GROTH16_BN254_NATIVE)
+    }
+
+    /// Whether the KZG opening verification native over BN254 is enabled.
+    /// This is needed because of the introduction of new native function(s).
+    /// Lifetime: transient
+    const KZG_BN254_NATIVE: u64 = 69;
+
+    public fun get_kzg_bn254_feature(): u64 { KZG_BN254_NATIVE }
+
+    public fun kzg_bn254_enabled(): bool acquires Features {
+        is_enabled(KZG_BN254_NATIVE)
+    }
+
+    /// Whether the modular exponentiation native is enabled.
+    /// This is needed because of the introduction of a new native function.
+    /// Lifetime: transient
+    const MODEXP_NATIVE: u64 = 70;
+
+    public fun get_modexp_feature(): u64 { MODEXP_NATIVE }
+
+    public fun modexp_enabled(): bool acquires Features {
+        is_enabled(MODEXP_NATIVE)
+    }
+
+    /// Whether the generic algebra implementation for BLS12-381 `Fq` and `Fq2` operations are enabled.
+    ///
+    /// Lifetime: transient
+    const BLS12_381_BASE_FIELD_STRUCTURES: u64 = 71;
+
+    public fun get_bls12_381_base_field_structures_feature(): u64 { BLS12_381_BASE_FIELD_STRUCTURES }
+
+    public fun bls12_381_base_field_structures_enabled(): bool acquires Features {
+        is_enabled(BLS12_381_BASE_FIELD_STRUCTURES)
+    }
+
+    /// Whether the SHA-256 compression function native is enabled.
+    /// This is needed because of the introduction of a new native function.
+    /// Lifetime: transient
+    const SHA256_COMPRESS_NATIVE: u64 = 72;
+
+    public fun get_sha256_compress_feature(): u64 { SHA256_COMPRESS_NATIVE }
+
+    public fun sha256_compress_enabled(): bool acquires Features {
+        is_enabled(SHA256_COMPRESS_NATIVE)
+    }
+
+    /// Whether the `crypto_algebra::deserialize_checked` native is enabled.
+    /// This is needed because of the introduction of a new native function.
+    /// Lifetime: transient
+    const ALGEBRA_DESERIALIZE_CHECKED_NATIVE: u64 = 73;
+
+    public fun get_algebra_deserialize_checked_feature(): u64 { ALGEBRA_DESERIALIZE_CHECKED_NATIVE }
+
+    public fun algebra_deserialize_checked_enabled(): bool acquires Features {
+        is_enabled(ALGEBRA_DESERIALIZE_CHECKED_NATIVE)
+    }
+
+    /// Whether the `crypto_algebra::fq12_cyclotomic_pow` native is enabled.
+    /// This is needed because of the introduction of a new native function.
+    /// Lifetime: transient
+    const BN254_FQ12_CYCLOTOMIC_POW_NATIVE: u64 = 74;
+
+    public fun get_bn254_fq12_cyclotomic_pow_feature(): u64 { BN254_FQ12_CYCLOTOMIC_POW_NATIVE }
+
+    public fun bn254_fq12_cyclotomic_pow_enabled(): bool acquires Features {
+        is_enabled(BN254_FQ12_CYCLOTOMIC_POW_NATIVE)
+    }
+
+    /// Whether the `bn254_algebra::bn254_fr_modulus` and `bn254_algebra::bn254_fq_modulus` natives are enabled.
+    /// This is needed because of the introduction of new native functions.
+    /// Lifetime: transient
+    const BN254_MODULI_NATIVE: u64 = 75;
+
+    public fun get_bn254_moduli_feature(): u64 { BN254_MODULI_NATIVE }
+
+    public fun bn254_moduli_enabled(): bool acquires Features {
+        is_enabled(BN254_MODULI_NATIVE)
+    }
+
+    /// Whether the `bn254_algebra::bn254_fr_to_bytes_*` and `bn254_algebra::bn254_fr_from_bytes_*` natives are enabled.
+    /// This is needed because of the introduction of new native functions.
+    /// Lifetime: transient
+    const BN254_FR_BYTES_NATIVE: u64 = 76;
+
+    public fun get_bn254_fr_bytes_feature(): u64 { BN254_FR_BYTES_NATIVE }
+
+    public fun bn254_fr_bytes_enabled(): bool acquires Features {
+        is_enabled(BN254_FR_BYTES_NATIVE)
+    }
+
+    /// Whether the `bn254_algebra::bn254_fr_from_transcript` native is enabled.
+    /// This is needed because of the introduction of a new native function.
+    /// Lifetime: transient
+    const BN254_FR_FROM_TRANSCRIPT_NATIVE: u64 = 77;
+
+    public fun get_bn254_fr_from_transcript_feature(): u64 { BN254_FR_FROM_TRANSCRIPT_NATIVE }
+
+    public fun bn254_fr_from_transcript_enabled(): bool acquires Features {
+        is_enabled(BN254_FR_FROM_TRANSCRIPT_NATIVE)
+    }
+
+    /// Whether the `crypto_algebra::is_zero` and `crypto_algebra::is_one` natives are enabled.
+    /// This is needed because of the introduction of new native functions.
+    /// Lifetime: transient
+    const ALGEBRA_IS_ZERO_IS_ONE_NATIVES: u64 = 78;
+
+    public fun get_algebra_is_zero_is_one_feature(): u64 { ALGEBRA_IS_ZERO_IS_ONE_NATIVES }
+
+    public fun algebra_is_zero_is_one_enabled(): bool acquires Features {
+        is_enabled(ALGEBRA_IS_ZERO_IS_ONE_NATIVES)
+    }
+
+    /// Whether the `crypto_algebra::pow_u64` native is enabled.
+    /// This is needed because of the introduction of a new native function.
+    /// Lifetime: transient
+    const ALGEBRA_POW_U64_NATIVE: u64 = 79;
+
+    public fun get_algebra_pow_u64_feature(): u64 { ALGEBRA_POW_U64_NATIVE }
+
+    public fun algebra_pow_u64_enabled(): bool acquires Features {
+        is_enabled(ALGEBRA_POW_U64_NATIVE)
+    }
+
     // ============================================================================================
     // Feature Flag Implementation
 
@@ -5,7 +5,7 @@ use crate::{
     abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
     ark_binary_op_internal,
     natives::cryptography::algebra::{
-        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, Structure,
+        abort_invariant_violated, feature_flag_from_structure, grumpkin, AlgebraContext, Structure,
         E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
     },
     safe_borrow_element, store_element, structure_from_ty_arg,
@@ -36,6 +36,20 @@ pub fn add_internal(
             add,
             ALGEBRA_ARK_BLS12_381_FR_ADD
         ),
+        Some(Structure::BLS12381Fq) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq,
+            add,
+            ALGEBRA_ARK_BLS12_381_FQ_ADD
+        ),
+        Some(Structure::BLS12381Fq2) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq2,
+            add,
+            ALGEBRA_ARK_BLS12_381_FQ2_ADD
+        ),
         Some(Structure::BLS12381Fq12) => ark_binary_op_internal!(
             context,
             args,
@@ -98,6 +112,111 @@ pub fn add_internal(
             mul,
             ALGEBRA_ARK_BN254_FQ12_MUL
         ),
+        Some(Structure::Secp256k1Fr) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_secp256k1::Fr,
+            add,
+            ALGEBRA_ARK_SECP256K1_FR_ADD
+        ),
+        Some(Structure::Secp256k1Fq) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_secp256k1::Fq,
+            add,
+            ALGEBRA_ARK_SECP256K1_FQ_ADD
+        ),
+        Some(Structure::Secp256k1G1) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_secp256k1::Projective,
+            add,
+            ALGEBRA_ARK_SECP256K1_G1_PROJ_ADD
+        ),
+        Some(Structure::Secp256r1Fr) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_secp256r1::Fr,
+            add,
+            ALGEBRA_ARK_SECP256R1_FR_ADD
+        ),
+        Some(Structure::Secp256r1Fq) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_secp256r1::Fq,
+            add,
+            ALGEBRA_ARK_SECP256R1_FQ_ADD
+        ),
+        Some(Structure::Secp256r1G1) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_secp256r1::Projective,
+            add,
+            ALGEBRA_ARK_SECP256R1_G1_PROJ_ADD
+        ),
+        Some(Structure::GrumpkinFr) => {
+            ark_binary_op_internal!(
+                context,
+                args,
+                grumpkin::Fr,
+                add,
+                ALGEBRA_ARK_GRUMPKIN_FR_ADD
+            )
+        },
+        Some(Structure::GrumpkinFq) => {
+            ark_binary_op_internal!(
+                context,
+                args,
+                grumpkin::Fq,
+                add,
+                ALGEBRA_ARK_GRUMPKIN_FQ_ADD
+            )
+        },
+        Some(Structure::GrumpkinG1) => ark_binary_op_internal!(
+            context,
+            args,
+            grumpkin::Projective,
+            add,
+            ALGEBRA_ARK_GRUMPKIN_G1_PROJ_ADD
+        ),
+        Some(Structure::PallasFr) => {
+            ark_binary_op_internal!(
+                context,
+                args,
+                ark_pallas::Fr,
+                add,
+                ALGEBRA_ARK_PALLAS_FR_ADD
+            )
+        },
+        Some(Structure::PallasFq) => {
+            ark_binary_op_internal!(
+                context,
+                args,
+                ark_pallas::Fq,
+                add,
+                ALGEBRA_ARK_PALLAS_FQ_ADD
+            )
+        },
+        Some(Structure::PallasG1) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_pallas::Projective,
+            add,
+            ALGEBRA_ARK_PALLAS_G1_PROJ_ADD
+        ),
+        Some(Structure::VestaFr) => {
+            ark_binary_op_internal!(context, args, ark_vesta::Fr, add, ALGEBRA_ARK_VESTA_FR_ADD)
+        },
+        Some(Structure::VestaFq) => {
+            ark_binary_op_internal!(context, args, ark_vesta::Fq, add, ALGEBRA_ARK_VESTA_FQ_ADD)
+        },
+        Some(Structure::VestaG1) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_vesta::Projective,
+            add,
+            ALGEBRA_ARK_VESTA_G1_PROJ_ADD
+        ),
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
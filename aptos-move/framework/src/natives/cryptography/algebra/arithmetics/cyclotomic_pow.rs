@@ -0,0 +1,62 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
+    natives::cryptography::algebra::{
+        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, Structure,
+        E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        MOVE_ABORT_CODE_NOT_IN_CYCLOTOMIC_SUBGROUP,
+    },
+    safe_borrow_element, store_element, structure_from_ty_arg,
+};
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_pop_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
+};
+use aptos_types::on_chain_config::FeatureFlag;
+use ark_ff::{CyclotomicMultSubgroup, Field, Zero};
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use smallvec::{smallvec, SmallVec};
+use std::{collections::VecDeque, rc::Rc};
+
+/// Compute `x^e` for an `Fq12` element `x` in the cyclotomic subgroup, e.g., a pairing output,
+/// using cyclotomic squarings instead of generic ones.
+/// Abort with `MOVE_ABORT_CODE_NOT_IN_CYCLOTOMIC_SUBGROUP` if `x` is not in the cyclotomic subgroup,
+/// where cyclotomic squarings would give a wrong result.
+pub fn fq12_cyclotomic_pow_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(1, ty_args.len());
+    abort_unless_feature_flag_enabled!(
+        context,
+        Some(FeatureFlag::BN254_FQ12_CYCLOTOMIC_POW_NATIVE)
+    );
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    abort_unless_arithmetics_enabled_for_structure!(context, structure_opt);
+    match structure_opt {
+        Some(Structure::BN254Fq12) => {
+            let exponent = safely_pop_arg!(args, u64);
+            let handle = safely_pop_arg!(args, u64) as usize;
+            safe_borrow_element!(context, handle, ark_bn254::Fq12, element_ptr, element);
+            context.charge(ALGEBRA_ARK_BN254_FQ12_CYCLOTOMIC_POW_U64)?;
+            // For BN254 the cyclotomic subgroup has order `q^4 - q^2 + 1`,
+            // so a non-zero `x` is in it iff `x^(q^4) * x == x^(q^2)`.
+            let element_q2 = element.frobenius_map(2);
+            let element_q4 = element_q2.frobenius_map(2);
+            if element.is_zero() || element_q4 * element != element_q2 {
+                return Err(SafeNativeError::Abort {
+                    abort_code: MOVE_ABORT_CODE_NOT_IN_CYCLOTOMIC_SUBGROUP,
+                });
+            }
+            let new_element = element.cyclotomic_exp([exponent]);
+            let new_handle = store_element!(context, new_element)?;
+            Ok(smallvec![Value::u64(new_handle as u64)])
+        },
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
@@ -4,7 +4,7 @@
 use crate::{
     abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
     natives::cryptography::algebra::{
-        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, Structure,
+        abort_invariant_violated, feature_flag_from_structure, grumpkin, AlgebraContext, Structure,
         E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
     },
     safe_borrow_element, store_element, structure_from_ty_arg,
@@ -52,6 +52,22 @@ pub fn div_internal(
             ALGEBRA_ARK_BLS12_381_FR_EQ,
             ALGEBRA_ARK_BLS12_381_FR_DIV
         ),
+        Some(Structure::BLS12381Fq) => ark_div_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq,
+            div,
+            ALGEBRA_ARK_BLS12_381_FQ_EQ,
+            ALGEBRA_ARK_BLS12_381_FQ_DIV
+        ),
+        Some(Structure::BLS12381Fq2) => ark_div_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq2,
+            div,
+            ALGEBRA_ARK_BLS12_381_FQ2_EQ,
+            ALGEBRA_ARK_BLS12_381_FQ2_DIV
+        ),
         Some(Structure::BLS12381Fq12) => ark_div_internal!(
             context,
             args,
@@ -84,6 +100,86 @@ pub fn div_internal(
             ALGEBRA_ARK_BN254_FQ12_EQ,
             ALGEBRA_ARK_BN254_FQ12_DIV
         ),
+        Some(Structure::Secp256k1Fr) => ark_div_internal!(
+            context,
+            args,
+            ark_secp256k1::Fr,
+            div,
+            ALGEBRA_ARK_SECP256K1_FR_EQ,
+            ALGEBRA_ARK_SECP256K1_FR_DIV
+        ),
+        Some(Structure::Secp256k1Fq) => ark_div_internal!(
+            context,
+            args,
+            ark_secp256k1::Fq,
+            div,
+            ALGEBRA_ARK_SECP256K1_FQ_EQ,
+            ALGEBRA_ARK_SECP256K1_FQ_DIV
+        ),
+        Some(Structure::Secp256r1Fr) => ark_div_internal!(
+            context,
+            args,
+            ark_secp256r1::Fr,
+            div,
+            ALGEBRA_ARK_SECP256R1_FR_EQ,
+            ALGEBRA_ARK_SECP256R1_FR_DIV
+        ),
+        Some(Structure::Secp256r1Fq) => ark_div_internal!(
+            context,
+            args,
+            ark_secp256r1::Fq,
+            div,
+            ALGEBRA_ARK_SECP256R1_FQ_EQ,
+            ALGEBRA_ARK_SECP256R1_FQ_DIV
+        ),
+        Some(Structure::GrumpkinFr) => ark_div_internal!(
+            context,
+            args,
+            grumpkin::Fr,
+            div,
+            ALGEBRA_ARK_GRUMPKIN_FR_EQ,
+            ALGEBRA_ARK_GRUMPKIN_FR_DIV
+        ),
+        Some(Structure::GrumpkinFq) => ark_div_internal!(
+            context,
+            args,
+            grumpkin::Fq,
+            div,
+            ALGEBRA_ARK_GRUMPKIN_FQ_EQ,
+            ALGEBRA_ARK_GRUMPKIN_FQ_DIV
+        ),
+        Some(Structure::PallasFr) => ark_div_internal!(
+            context,
+            args,
+            ark_pallas::Fr,
+            div,
+            ALGEBRA_ARK_PALLAS_FR_EQ,
+            ALGEBRA_ARK_PALLAS_FR_DIV
+        ),
+        Some(Structure::PallasFq) => ark_div_internal!(
+            context,
+            args,
+            ark_pallas::Fq,
+            div,
+            ALGEBRA_ARK_PALLAS_FQ_EQ,
+            ALGEBRA_ARK_PALLAS_FQ_DIV
+        ),
+        Some(Structure::VestaFr) => ark_div_internal!(
+            context,
+            args,
+            ark_vesta::Fr,
+            div,
+            ALGEBRA_ARK_VESTA_FR_EQ,
+            ALGEBRA_ARK_VESTA_FR_DIV
+        ),
+        Some(Structure::VestaFq) => ark_div_internal!(
+            context,
+            args,
+            ark_vesta::Fq,
+            div,
+            ALGEBRA_ARK_VESTA_FQ_EQ,
+            ALGEBRA_ARK_VESTA_FQ_DIV
+        ),
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
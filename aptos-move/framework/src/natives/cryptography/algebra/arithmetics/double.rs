@@ -5,7 +5,7 @@ use crate::{
     abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
     ark_unary_op_internal,
     natives::cryptography::algebra::{
-        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, Structure,
+        abort_invariant_violated, feature_flag_from_structure, grumpkin, AlgebraContext, Structure,
         E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
     },
     safe_borrow_element, store_element, structure_from_ty_arg,
@@ -69,6 +69,41 @@ pub fn double_internal(
             square,
             ALGEBRA_ARK_BN254_FQ12_SQUARE
         ),
+        Some(Structure::Secp256k1G1) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_secp256k1::Projective,
+            double,
+            ALGEBRA_ARK_SECP256K1_G1_PROJ_DOUBLE
+        ),
+        Some(Structure::Secp256r1G1) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_secp256r1::Projective,
+            double,
+            ALGEBRA_ARK_SECP256R1_G1_PROJ_DOUBLE
+        ),
+        Some(Structure::GrumpkinG1) => ark_unary_op_internal!(
+            context,
+            args,
+            grumpkin::Projective,
+            double,
+            ALGEBRA_ARK_GRUMPKIN_G1_PROJ_DOUBLE
+        ),
+        Some(Structure::PallasG1) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_pallas::Projective,
+            double,
+            ALGEBRA_ARK_PALLAS_G1_PROJ_DOUBLE
+        ),
+        Some(Structure::VestaG1) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_vesta::Projective,
+            double,
+            ALGEBRA_ARK_VESTA_G1_PROJ_DOUBLE
+        ),
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
@@ -4,7 +4,7 @@
 use crate::{
     abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
     natives::cryptography::algebra::{
-        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, Structure,
+        abort_invariant_violated, feature_flag_from_structure, grumpkin, AlgebraContext, Structure,
         E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
     },
     safe_borrow_element, store_element, structure_from_ty_arg,
@@ -47,6 +47,18 @@ pub fn inv_internal(
             ark_bls12_381::Fr,
             ALGEBRA_ARK_BLS12_381_FR_INV
         ),
+        Some(Structure::BLS12381Fq) => ark_inverse_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq,
+            ALGEBRA_ARK_BLS12_381_FQ_INV
+        ),
+        Some(Structure::BLS12381Fq2) => ark_inverse_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq2,
+            ALGEBRA_ARK_BLS12_381_FQ2_INV
+        ),
         Some(Structure::BLS12381Fq12) => ark_inverse_internal!(
             context,
             args,
@@ -62,6 +74,56 @@ pub fn inv_internal(
         Some(Structure::BN254Fq12) => {
             ark_inverse_internal!(context, args, ark_bn254::Fq12, ALGEBRA_ARK_BN254_FQ12_INV)
         },
+        Some(Structure::Secp256k1Fr) => {
+            ark_inverse_internal!(
+                context,
+                args,
+                ark_secp256k1::Fr,
+                ALGEBRA_ARK_SECP256K1_FR_INV
+            )
+        },
+        Some(Structure::Secp256k1Fq) => {
+            ark_inverse_internal!(
+                context,
+                args,
+                ark_secp256k1::Fq,
+                ALGEBRA_ARK_SECP256K1_FQ_INV
+            )
+        },
+        Some(Structure::Secp256r1Fr) => {
+            ark_inverse_internal!(
+                context,
+                args,
+                ark_secp256r1::Fr,
+                ALGEBRA_ARK_SECP256R1_FR_INV
+            )
+        },
+        Some(Structure::Secp256r1Fq) => {
+            ark_inverse_internal!(
+                context,
+                args,
+                ark_secp256r1::Fq,
+                ALGEBRA_ARK_SECP256R1_FQ_INV
+            )
+        },
+        Some(Structure::GrumpkinFr) => {
+            ark_inverse_internal!(context, args, grumpkin::Fr, ALGEBRA_ARK_GRUMPKIN_FR_INV)
+        },
+        Some(Structure::GrumpkinFq) => {
+            ark_inverse_internal!(context, args, grumpkin::Fq, ALGEBRA_ARK_GRUMPKIN_FQ_INV)
+        },
+        Some(Structure::PallasFr) => {
+            ark_inverse_internal!(context, args, ark_pallas::Fr, ALGEBRA_ARK_PALLAS_FR_INV)
+        },
+        Some(Structure::PallasFq) => {
+            ark_inverse_internal!(context, args, ark_pallas::Fq, ALGEBRA_ARK_PALLAS_FQ_INV)
+        },
+        Some(Structure::VestaFr) => {
+            ark_inverse_internal!(context, args, ark_vesta::Fr, ALGEBRA_ARK_VESTA_FR_INV)
+        },
+        Some(Structure::VestaFq) => {
+            ark_inverse_internal!(context, args, ark_vesta::Fq, ALGEBRA_ARK_VESTA_FQ_INV)
+        },
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
@@ -2,11 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod add;
+pub mod cyclotomic_pow;
 pub mod div;
 pub mod double;
 pub mod inv;
 pub mod mul;
 pub mod neg;
+pub mod pow;
 pub mod scalar_mul;
 pub mod sqr;
 pub mod sub;
@@ -5,7 +5,7 @@ use crate::{
     abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
     ark_binary_op_internal,
     natives::cryptography::algebra::{
-        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, Structure,
+        abort_invariant_violated, feature_flag_from_structure, grumpkin, AlgebraContext, Structure,
         E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
     },
     safe_borrow_element, store_element, structure_from_ty_arg,
@@ -32,6 +32,20 @@ pub fn mul_internal(
             mul,
             ALGEBRA_ARK_BLS12_381_FR_MUL
         ),
+        Some(Structure::BLS12381Fq) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq,
+            mul,
+            ALGEBRA_ARK_BLS12_381_FQ_MUL
+        ),
+        Some(Structure::BLS12381Fq2) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq2,
+            mul,
+            ALGEBRA_ARK_BLS12_381_FQ2_MUL
+        ),
         Some(Structure::BLS12381Fq12) => ark_binary_op_internal!(
             context,
             args,
@@ -54,6 +68,76 @@ pub fn mul_internal(
                 ALGEBRA_ARK_BN254_FQ12_MUL
             )
         },
+        Some(Structure::Secp256k1Fr) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_secp256k1::Fr,
+            mul,
+            ALGEBRA_ARK_SECP256K1_FR_MUL
+        ),
+        Some(Structure::Secp256k1Fq) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_secp256k1::Fq,
+            mul,
+            ALGEBRA_ARK_SECP256K1_FQ_MUL
+        ),
+        Some(Structure::Secp256r1Fr) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_secp256r1::Fr,
+            mul,
+            ALGEBRA_ARK_SECP256R1_FR_MUL
+        ),
+        Some(Structure::Secp256r1Fq) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_secp256r1::Fq,
+            mul,
+            ALGEBRA_ARK_SECP256R1_FQ_MUL
+        ),
+        Some(Structure::GrumpkinFr) => {
+            ark_binary_op_internal!(
+                context,
+                args,
+                grumpkin::Fr,
+                mul,
+                ALGEBRA_ARK_GRUMPKIN_FR_MUL
+            )
+        },
+        Some(Structure::GrumpkinFq) => {
+            ark_binary_op_internal!(
+                context,
+                args,
+                grumpkin::Fq,
+                mul,
+                ALGEBRA_ARK_GRUMPKIN_FQ_MUL
+            )
+        },
+        Some(Structure::PallasFr) => {
+            ark_binary_op_internal!(
+                context,
+                args,
+                ark_pallas::Fr,
+                mul,
+                ALGEBRA_ARK_PALLAS_FR_MUL
+            )
+        },
+        Some(Structure::PallasFq) => {
+            ark_binary_op_internal!(
+                context,
+                args,
+                ark_pallas::Fq,
+                mul,
+                ALGEBRA_ARK_PALLAS_FQ_MUL
+            )
+        },
+        Some(Structure::VestaFr) => {
+            ark_binary_op_internal!(context, args, ark_vesta::Fr, mul, ALGEBRA_ARK_VESTA_FR_MUL)
+        },
+        Some(Structure::VestaFq) => {
+            ark_binary_op_internal!(context, args, ark_vesta::Fq, mul, ALGEBRA_ARK_VESTA_FQ_MUL)
+        },
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
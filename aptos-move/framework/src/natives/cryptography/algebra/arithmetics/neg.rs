@@ -5,7 +5,7 @@ use crate::{
     abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
     ark_unary_op_internal,
     natives::cryptography::algebra::{
-        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, Structure,
+        abort_invariant_violated, feature_flag_from_structure, grumpkin, AlgebraContext, Structure,
         E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
     },
     safe_borrow_element, store_element, structure_from_ty_arg,
@@ -35,6 +35,20 @@ pub fn neg_internal(
             neg,
             ALGEBRA_ARK_BLS12_381_FR_NEG
         ),
+        Some(Structure::BLS12381Fq) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq,
+            neg,
+            ALGEBRA_ARK_BLS12_381_FQ_NEG
+        ),
+        Some(Structure::BLS12381Fq2) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq2,
+            neg,
+            ALGEBRA_ARK_BLS12_381_FQ2_NEG
+        ),
         Some(Structure::BLS12381Fq12) => ark_unary_op_internal!(
             context,
             args,
@@ -99,6 +113,111 @@ pub fn neg_internal(
             let new_handle = store_element!(context, new_element)?;
             Ok(smallvec![Value::u64(new_handle as u64)])
         },
+        Some(Structure::Secp256k1Fr) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_secp256k1::Fr,
+            neg,
+            ALGEBRA_ARK_SECP256K1_FR_NEG
+        ),
+        Some(Structure::Secp256k1Fq) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_secp256k1::Fq,
+            neg,
+            ALGEBRA_ARK_SECP256K1_FQ_NEG
+        ),
+        Some(Structure::Secp256k1G1) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_secp256k1::Projective,
+            neg,
+            ALGEBRA_ARK_SECP256K1_G1_PROJ_NEG
+        ),
+        Some(Structure::Secp256r1Fr) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_secp256r1::Fr,
+            neg,
+            ALGEBRA_ARK_SECP256R1_FR_NEG
+        ),
+        Some(Structure::Secp256r1Fq) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_secp256r1::Fq,
+            neg,
+            ALGEBRA_ARK_SECP256R1_FQ_NEG
+        ),
+        Some(Structure::Secp256r1G1) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_secp256r1::Projective,
+            neg,
+            ALGEBRA_ARK_SECP256R1_G1_PROJ_NEG
+        ),
+        Some(Structure::GrumpkinFr) => {
+            ark_unary_op_internal!(
+                context,
+                args,
+                grumpkin::Fr,
+                neg,
+                ALGEBRA_ARK_GRUMPKIN_FR_NEG
+            )
+        },
+        Some(Structure::GrumpkinFq) => {
+            ark_unary_op_internal!(
+                context,
+                args,
+                grumpkin::Fq,
+                neg,
+                ALGEBRA_ARK_GRUMPKIN_FQ_NEG
+            )
+        },
+        Some(Structure::GrumpkinG1) => ark_unary_op_internal!(
+            context,
+            args,
+            grumpkin::Projective,
+            neg,
+            ALGEBRA_ARK_GRUMPKIN_G1_PROJ_NEG
+        ),
+        Some(Structure::PallasFr) => {
+            ark_unary_op_internal!(
+                context,
+                args,
+                ark_pallas::Fr,
+                neg,
+                ALGEBRA_ARK_PALLAS_FR_NEG
+            )
+        },
+        Some(Structure::PallasFq) => {
+            ark_unary_op_internal!(
+                context,
+                args,
+                ark_pallas::Fq,
+                neg,
+                ALGEBRA_ARK_PALLAS_FQ_NEG
+            )
+        },
+        Some(Structure::PallasG1) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_pallas::Projective,
+            neg,
+            ALGEBRA_ARK_PALLAS_G1_PROJ_NEG
+        ),
+        Some(Structure::VestaFr) => {
+            ark_unary_op_internal!(context, args, ark_vesta::Fr, neg, ALGEBRA_ARK_VESTA_FR_NEG)
+        },
+        Some(Structure::VestaFq) => {
+            ark_unary_op_internal!(context, args, ark_vesta::Fq, neg, ALGEBRA_ARK_VESTA_FQ_NEG)
+        },
+        Some(Structure::VestaG1) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_vesta::Projective,
+            neg,
+            ALGEBRA_ARK_VESTA_G1_PROJ_NEG
+        ),
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
@@ -0,0 +1,183 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
+    natives::cryptography::algebra::{
+        abort_invariant_violated, feature_flag_from_structure, grumpkin, AlgebraContext, Structure,
+        E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+    },
+    safe_borrow_element, store_element, structure_from_ty_arg,
+};
+use aptos_gas_algebra::{Arg, GasExpression};
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_pop_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
+};
+use aptos_types::on_chain_config::FeatureFlag;
+use ark_ff::Field;
+use move_core_types::gas_algebra::NumArgs;
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use smallvec::{smallvec, SmallVec};
+use std::{collections::VecDeque, rc::Rc};
+
+/// The cost of `ark_ff::Field::pow`, which does one squaring per bit of the exponent
+/// and one multiplication per set bit.
+/// At least one multiplication is charged so that `x^0` is not free.
+macro_rules! ark_pow_u64_cost {
+    ($cost_sqr:expr, $cost_mul:expr, $exponent:expr $(,)?) => {{
+        let exponent: u64 = $exponent;
+        let num_bits = (u64::BITS - exponent.leading_zeros()) as u64;
+        let num_ones = std::cmp::max(exponent.count_ones() as u64, 1);
+        $cost_sqr.per::<Arg>() * NumArgs::from(num_bits)
+            + $cost_mul.per::<Arg>() * NumArgs::from(num_ones)
+    }};
+}
+
+macro_rules! ark_pow_u64_internal {
+    ($context:expr, $args:ident, $ark_typ:ty, $cost_sqr:expr, $cost_mul:expr) => {{
+        let exponent = safely_pop_arg!($args, u64);
+        let handle = safely_pop_arg!($args, u64) as usize;
+        safe_borrow_element!($context, handle, $ark_typ, element_ptr, element);
+        $context.charge(ark_pow_u64_cost!($cost_sqr, $cost_mul, exponent))?;
+        let new_element = element.pow([exponent]);
+        let new_handle = store_element!($context, new_element)?;
+        Ok(smallvec![Value::u64(new_handle as u64)])
+    }};
+}
+
+/// Compute `x^e` for a field element `x` and a `u64` exponent `e` by square-and-multiply.
+pub fn pow_u64_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(1, ty_args.len());
+    abort_unless_feature_flag_enabled!(context, Some(FeatureFlag::ALGEBRA_POW_U64_NATIVE));
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    abort_unless_arithmetics_enabled_for_structure!(context, structure_opt);
+    match structure_opt {
+        Some(Structure::BLS12381Fr) => ark_pow_u64_internal!(
+            context,
+            args,
+            ark_bls12_381::Fr,
+            ALGEBRA_ARK_BLS12_381_FR_SQUARE,
+            ALGEBRA_ARK_BLS12_381_FR_MUL
+        ),
+        Some(Structure::BLS12381Fq) => ark_pow_u64_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq,
+            ALGEBRA_ARK_BLS12_381_FQ_SQUARE,
+            ALGEBRA_ARK_BLS12_381_FQ_MUL
+        ),
+        Some(Structure::BLS12381Fq2) => ark_pow_u64_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq2,
+            ALGEBRA_ARK_BLS12_381_FQ2_SQUARE,
+            ALGEBRA_ARK_BLS12_381_FQ2_MUL
+        ),
+        Some(Structure::BLS12381Fq12) => ark_pow_u64_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq12,
+            ALGEBRA_ARK_BLS12_381_FQ12_SQUARE,
+            ALGEBRA_ARK_BLS12_381_FQ12_MUL
+        ),
+        Some(Structure::BN254Fr) => ark_pow_u64_internal!(
+            context,
+            args,
+            ark_bn254::Fr,
+            ALGEBRA_ARK_BN254_FR_SQUARE,
+            ALGEBRA_ARK_BN254_FR_MUL
+        ),
+        Some(Structure::BN254Fq) => ark_pow_u64_internal!(
+            context,
+            args,
+            ark_bn254::Fq,
+            ALGEBRA_ARK_BN254_FQ_SQUARE,
+            ALGEBRA_ARK_BN254_FQ_MUL
+        ),
+        Some(Structure::BN254Fq12) => ark_pow_u64_internal!(
+            context,
+            args,
+            ark_bn254::Fq12,
+            ALGEBRA_ARK_BN254_FQ12_SQUARE,
+            ALGEBRA_ARK_BN254_FQ12_MUL
+        ),
+        Some(Structure::Secp256k1Fr) => ark_pow_u64_internal!(
+            context,
+            args,
+            ark_secp256k1::Fr,
+            ALGEBRA_ARK_SECP256K1_FR_SQUARE,
+            ALGEBRA_ARK_SECP256K1_FR_MUL
+        ),
+        Some(Structure::Secp256k1Fq) => ark_pow_u64_internal!(
+            context,
+            args,
+            ark_secp256k1::Fq,
+            ALGEBRA_ARK_SECP256K1_FQ_SQUARE,
+            ALGEBRA_ARK_SECP256K1_FQ_MUL
+        ),
+        Some(Structure::Secp256r1Fr) => ark_pow_u64_internal!(
+            context,
+            args,
+            ark_secp256r1::Fr,
+            ALGEBRA_ARK_SECP256R1_FR_SQUARE,
+            ALGEBRA_ARK_SECP256R1_FR_MUL
+        ),
+        Some(Structure::Secp256r1Fq) => ark_pow_u64_internal!(
+            context,
+            args,
+            ark_secp256r1::Fq,
+            ALGEBRA_ARK_SECP256R1_FQ_SQUARE,
+            ALGEBRA_ARK_SECP256R1_FQ_MUL
+        ),
+        Some(Structure::GrumpkinFr) => ark_pow_u64_internal!(
+            context,
+            args,
+            grumpkin::Fr,
+            ALGEBRA_ARK_GRUMPKIN_FR_SQUARE,
+            ALGEBRA_ARK_GRUMPKIN_FR_MUL
+        ),
+        Some(Structure::GrumpkinFq) => ark_pow_u64_internal!(
+            context,
+            args,
+            grumpkin::Fq,
+            ALGEBRA_ARK_GRUMPKIN_FQ_SQUARE,
+            ALGEBRA_ARK_GRUMPKIN_FQ_MUL
+        ),
+        Some(Structure::PallasFr) => ark_pow_u64_internal!(
+            context,
+            args,
+            ark_pallas::Fr,
+            ALGEBRA_ARK_PALLAS_FR_SQUARE,
+            ALGEBRA_ARK_PALLAS_FR_MUL
+        ),
+        Some(Structure::PallasFq) => ark_pow_u64_internal!(
+            context,
+            args,
+            ark_pallas::Fq,
+            ALGEBRA_ARK_PALLAS_FQ_SQUARE,
+            ALGEBRA_ARK_PALLAS_FQ_MUL
+        ),
+        Some(Structure::VestaFr) => ark_pow_u64_internal!(
+            context,
+            args,
+            ark_vesta::Fr,
+            ALGEBRA_ARK_VESTA_FR_SQUARE,
+            ALGEBRA_ARK_VESTA_FR_MUL
+        ),
+        Some(Structure::VestaFq) => ark_pow_u64_internal!(
+            context,
+            args,
+            ark_vesta::Fq,
+            ALGEBRA_ARK_VESTA_FQ_SQUARE,
+            ALGEBRA_ARK_VESTA_FQ_MUL
+        ),
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
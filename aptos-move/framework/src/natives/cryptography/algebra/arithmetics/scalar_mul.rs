@@ -5,7 +5,7 @@ use crate::{
     abort_unless_feature_flag_enabled,
     natives::cryptography::{
         algebra::{
-            abort_invariant_violated, AlgebraContext, Structure, E_TOO_MUCH_MEMORY_USED,
+            abort_invariant_violated, grumpkin, AlgebraContext, Structure, E_TOO_MUCH_MEMORY_USED,
             MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_INPUT_VECTOR_SIZES_NOT_MATCHING,
             MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         },
@@ -41,6 +41,17 @@ fn feature_flag_of_group_scalar_mul(
         | (Some(Structure::BN254Gt), Some(Structure::BN254Fr)) => {
             Some(FeatureFlag::BN254_STRUCTURES)
         },
+        (Some(Structure::Secp256k1G1), Some(Structure::Secp256k1Fr))
+        | (Some(Structure::Secp256r1G1), Some(Structure::Secp256r1Fr)) => {
+            Some(FeatureFlag::SECP256K1_SECP256R1_STRUCTURES)
+        },
+        (Some(Structure::GrumpkinG1), Some(Structure::GrumpkinFr)) => {
+            Some(FeatureFlag::GRUMPKIN_STRUCTURES)
+        },
+        (Some(Structure::PallasG1), Some(Structure::PallasFr))
+        | (Some(Structure::VestaG1), Some(Structure::VestaFr)) => {
+            Some(FeatureFlag::PASTA_STRUCTURES)
+        },
 
         _ => None,
     }
@@ -178,6 +189,56 @@ pub fn scalar_mul_internal(
             let new_handle = store_element!(context, new_element)?;
             Ok(smallvec![Value::u64(new_handle as u64)])
         },
+        (Some(Structure::Secp256k1G1), Some(Structure::Secp256k1Fr)) => {
+            ark_scalar_mul_internal!(
+                context,
+                args,
+                ark_secp256k1::Projective,
+                ark_secp256k1::Fr,
+                mul_bigint,
+                ALGEBRA_ARK_SECP256K1_G1_PROJ_SCALAR_MUL
+            )
+        },
+        (Some(Structure::Secp256r1G1), Some(Structure::Secp256r1Fr)) => {
+            ark_scalar_mul_internal!(
+                context,
+                args,
+                ark_secp256r1::Projective,
+                ark_secp256r1::Fr,
+                mul_bigint,
+                ALGEBRA_ARK_SECP256R1_G1_PROJ_SCALAR_MUL
+            )
+        },
+        (Some(Structure::GrumpkinG1), Some(Structure::GrumpkinFr)) => {
+            ark_scalar_mul_internal!(
+                context,
+                args,
+                grumpkin::Projective,
+                grumpkin::Fr,
+                mul_bigint,
+                ALGEBRA_ARK_GRUMPKIN_G1_PROJ_SCALAR_MUL
+            )
+        },
+        (Some(Structure::PallasG1), Some(Structure::PallasFr)) => {
+            ark_scalar_mul_internal!(
+                context,
+                args,
+                ark_pallas::Projective,
+                ark_pallas::Fr,
+                mul_bigint,
+                ALGEBRA_ARK_PALLAS_G1_PROJ_SCALAR_MUL
+            )
+        },
+        (Some(Structure::VestaG1), Some(Structure::VestaFr)) => {
+            ark_scalar_mul_internal!(
+                context,
+                args,
+                ark_vesta::Projective,
+                ark_vesta::Fr,
+                mul_bigint,
+                ALGEBRA_ARK_VESTA_G1_PROJ_SCALAR_MUL
+            )
+        },
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
@@ -286,6 +347,61 @@ pub fn multi_scalar_mul_internal(
                 ark_bn254::Fr
             )
         },
+        (Some(Structure::Secp256k1G1), Some(Structure::Secp256k1Fr)) => {
+            ark_msm_internal!(
+                context,
+                args,
+                ALGEBRA_ARK_SECP256K1_G1_PROJ_TO_AFFINE.per::<Arg>(),
+                ALGEBRA_ARK_SECP256K1_G1_PROJ_ADD.per::<Arg>(),
+                ALGEBRA_ARK_SECP256K1_G1_PROJ_DOUBLE.per::<Arg>(),
+                ark_secp256k1::Projective,
+                ark_secp256k1::Fr
+            )
+        },
+        (Some(Structure::Secp256r1G1), Some(Structure::Secp256r1Fr)) => {
+            ark_msm_internal!(
+                context,
+                args,
+                ALGEBRA_ARK_SECP256R1_G1_PROJ_TO_AFFINE.per::<Arg>(),
+                ALGEBRA_ARK_SECP256R1_G1_PROJ_ADD.per::<Arg>(),
+                ALGEBRA_ARK_SECP256R1_G1_PROJ_DOUBLE.per::<Arg>(),
+                ark_secp256r1::Projective,
+                ark_secp256r1::Fr
+            )
+        },
+        (Some(Structure::GrumpkinG1), Some(Structure::GrumpkinFr)) => {
+            ark_msm_internal!(
+                context,
+                args,
+                ALGEBRA_ARK_GRUMPKIN_G1_PROJ_TO_AFFINE.per::<Arg>(),
+                ALGEBRA_ARK_GRUMPKIN_G1_PROJ_ADD.per::<Arg>(),
+                ALGEBRA_ARK_GRUMPKIN_G1_PROJ_DOUBLE.per::<Arg>(),
+                grumpkin::Projective,
+                grumpkin::Fr
+            )
+        },
+        (Some(Structure::PallasG1), Some(Structure::PallasFr)) => {
+            ark_msm_internal!(
+                context,
+                args,
+                ALGEBRA_ARK_PALLAS_G1_PROJ_TO_AFFINE.per::<Arg>(),
+                ALGEBRA_ARK_PALLAS_G1_PROJ_ADD.per::<Arg>(),
+                ALGEBRA_ARK_PALLAS_G1_PROJ_DOUBLE.per::<Arg>(),
+                ark_pallas::Projective,
+                ark_pallas::Fr
+            )
+        },
+        (Some(Structure::VestaG1), Some(Structure::VestaFr)) => {
+            ark_msm_internal!(
+                context,
+                args,
+                ALGEBRA_ARK_VESTA_G1_PROJ_TO_AFFINE.per::<Arg>(),
+                ALGEBRA_ARK_VESTA_G1_PROJ_ADD.per::<Arg>(),
+                ALGEBRA_ARK_VESTA_G1_PROJ_DOUBLE.per::<Arg>(),
+                ark_vesta::Projective,
+                ark_vesta::Fr
+            )
+        },
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
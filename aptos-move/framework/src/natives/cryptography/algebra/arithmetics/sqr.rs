@@ -5,7 +5,7 @@ use crate::{
     abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
     ark_unary_op_internal,
     natives::cryptography::algebra::{
-        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, Structure,
+        abort_invariant_violated, feature_flag_from_structure, grumpkin, AlgebraContext, Structure,
         E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
     },
     safe_borrow_element, store_element, structure_from_ty_arg,
@@ -32,6 +32,20 @@ pub fn sqr_internal(
             square,
             ALGEBRA_ARK_BLS12_381_FR_SQUARE
         ),
+        Some(Structure::BLS12381Fq) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq,
+            square,
+            ALGEBRA_ARK_BLS12_381_FQ_SQUARE
+        ),
+        Some(Structure::BLS12381Fq2) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq2,
+            square,
+            ALGEBRA_ARK_BLS12_381_FQ2_SQUARE
+        ),
         Some(Structure::BLS12381Fq12) => ark_unary_op_internal!(
             context,
             args,
@@ -66,6 +80,76 @@ pub fn sqr_internal(
                 ALGEBRA_ARK_BN254_FQ12_SQUARE
             )
         },
+        Some(Structure::Secp256k1Fr) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_secp256k1::Fr,
+            square,
+            ALGEBRA_ARK_SECP256K1_FR_SQUARE
+        ),
+        Some(Structure::Secp256k1Fq) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_secp256k1::Fq,
+            square,
+            ALGEBRA_ARK_SECP256K1_FQ_SQUARE
+        ),
+        Some(Structure::Secp256r1Fr) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_secp256r1::Fr,
+            square,
+            ALGEBRA_ARK_SECP256R1_FR_SQUARE
+        ),
+        Some(Structure::Secp256r1Fq) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_secp256r1::Fq,
+            square,
+            ALGEBRA_ARK_SECP256R1_FQ_SQUARE
+        ),
+        Some(Structure::GrumpkinFr) => ark_unary_op_internal!(
+            context,
+            args,
+            grumpkin::Fr,
+            square,
+            ALGEBRA_ARK_GRUMPKIN_FR_SQUARE
+        ),
+        Some(Structure::GrumpkinFq) => ark_unary_op_internal!(
+            context,
+            args,
+            grumpkin::Fq,
+            square,
+            ALGEBRA_ARK_GRUMPKIN_FQ_SQUARE
+        ),
+        Some(Structure::PallasFr) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_pallas::Fr,
+            square,
+            ALGEBRA_ARK_PALLAS_FR_SQUARE
+        ),
+        Some(Structure::PallasFq) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_pallas::Fq,
+            square,
+            ALGEBRA_ARK_PALLAS_FQ_SQUARE
+        ),
+        Some(Structure::VestaFr) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_vesta::Fr,
+            square,
+            ALGEBRA_ARK_VESTA_FR_SQUARE
+        ),
+        Some(Structure::VestaFq) => ark_unary_op_internal!(
+            context,
+            args,
+            ark_vesta::Fq,
+            square,
+            ALGEBRA_ARK_VESTA_FQ_SQUARE
+        ),
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
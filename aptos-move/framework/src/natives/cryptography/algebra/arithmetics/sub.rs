@@ -5,7 +5,7 @@ use crate::{
     abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
     ark_binary_op_internal,
     natives::cryptography::algebra::{
-        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, Structure,
+        abort_invariant_violated, feature_flag_from_structure, grumpkin, AlgebraContext, Structure,
         E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
     },
     safe_borrow_element, store_element, structure_from_ty_arg,
@@ -36,6 +36,20 @@ pub fn sub_internal(
             sub,
             ALGEBRA_ARK_BLS12_381_FR_SUB
         ),
+        Some(Structure::BLS12381Fq) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq,
+            sub,
+            ALGEBRA_ARK_BLS12_381_FQ_SUB
+        ),
+        Some(Structure::BLS12381Fq2) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq2,
+            sub,
+            ALGEBRA_ARK_BLS12_381_FQ2_SUB
+        ),
         Some(Structure::BLS12381Fq12) => ark_binary_op_internal!(
             context,
             args,
@@ -99,6 +113,111 @@ pub fn sub_internal(
             div,
             ALGEBRA_ARK_BN254_FQ12_DIV
         ),
+        Some(Structure::Secp256k1Fr) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_secp256k1::Fr,
+            sub,
+            ALGEBRA_ARK_SECP256K1_FR_SUB
+        ),
+        Some(Structure::Secp256k1Fq) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_secp256k1::Fq,
+            sub,
+            ALGEBRA_ARK_SECP256K1_FQ_SUB
+        ),
+        Some(Structure::Secp256k1G1) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_secp256k1::Projective,
+            sub,
+            ALGEBRA_ARK_SECP256K1_G1_PROJ_SUB
+        ),
+        Some(Structure::Secp256r1Fr) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_secp256r1::Fr,
+            sub,
+            ALGEBRA_ARK_SECP256R1_FR_SUB
+        ),
+        Some(Structure::Secp256r1Fq) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_secp256r1::Fq,
+            sub,
+            ALGEBRA_ARK_SECP256R1_FQ_SUB
+        ),
+        Some(Structure::Secp256r1G1) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_secp256r1::Projective,
+            sub,
+            ALGEBRA_ARK_SECP256R1_G1_PROJ_SUB
+        ),
+        Some(Structure::GrumpkinFr) => {
+            ark_binary_op_internal!(
+                context,
+                args,
+                grumpkin::Fr,
+                sub,
+                ALGEBRA_ARK_GRUMPKIN_FR_SUB
+            )
+        },
+        Some(Structure::GrumpkinFq) => {
+            ark_binary_op_internal!(
+                context,
+                args,
+                grumpkin::Fq,
+                sub,
+                ALGEBRA_ARK_GRUMPKIN_FQ_SUB
+            )
+        },
+        Some(Structure::GrumpkinG1) => ark_binary_op_internal!(
+            context,
+            args,
+            grumpkin::Projective,
+            sub,
+            ALGEBRA_ARK_GRUMPKIN_G1_PROJ_SUB
+        ),
+        Some(Structure::PallasFr) => {
+            ark_binary_op_internal!(
+                context,
+                args,
+                ark_pallas::Fr,
+                sub,
+                ALGEBRA_ARK_PALLAS_FR_SUB
+            )
+        },
+        Some(Structure::PallasFq) => {
+            ark_binary_op_internal!(
+                context,
+                args,
+                ark_pallas::Fq,
+                sub,
+                ALGEBRA_ARK_PALLAS_FQ_SUB
+            )
+        },
+        Some(Structure::PallasG1) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_pallas::Projective,
+            sub,
+            ALGEBRA_ARK_PALLAS_G1_PROJ_SUB
+        ),
+        Some(Structure::VestaFr) => {
+            ark_binary_op_internal!(context, args, ark_vesta::Fr, sub, ALGEBRA_ARK_VESTA_FR_SUB)
+        },
+        Some(Structure::VestaFq) => {
+            ark_binary_op_internal!(context, args, ark_vesta::Fq, sub, ALGEBRA_ARK_VESTA_FQ_SUB)
+        },
+        Some(Structure::VestaG1) => ark_binary_op_internal!(
+            context,
+            args,
+            ark_vesta::Projective,
+            sub,
+            ALGEBRA_ARK_VESTA_G1_PROJ_SUB
+        ),
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
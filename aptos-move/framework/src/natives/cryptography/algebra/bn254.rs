@@ -0,0 +1,156 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Natives of `aptos_std::bn254_algebra` that are specific to BN254,
+//! as opposed to the generic ones of `aptos_std::crypto_algebra`.
+
+use crate::{
+    abort_unless_feature_flag_enabled,
+    natives::cryptography::algebra::{
+        abort_invariant_violated, BN254_Q_LENDIAN, BN254_R_LENDIAN,
+        MOVE_ABORT_CODE_NON_CANONICAL_ELEMENT, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+    },
+};
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_pop_arg, RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeError,
+    SafeNativeResult,
+};
+use aptos_types::on_chain_config::FeatureFlag;
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use move_core_types::{gas_algebra::NumBytes, u256::U256};
+use move_vm_runtime::native_functions::NativeFunction;
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use sha2::{Digest, Sha512};
+use smallvec::{smallvec, SmallVec};
+use std::collections::VecDeque;
+
+/// Domain separation tag prepended to the transcript in `bn254_fr_from_transcript_internal`.
+const FR_FROM_TRANSCRIPT_DST: &[u8] = b"APTOS_BN254_FR_FROM_TRANSCRIPT";
+
+fn u256_from_lendian(bytes: &[u8]) -> U256 {
+    // Both moduli are 254-bit, so their little-endian encodings are exactly 32 bytes,
+    // and so are canonical `Fr` encodings.
+    U256::from_le_bytes(bytes.try_into().expect("32 bytes"))
+}
+
+fn bn254_fr_modulus_internal(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    _args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    abort_unless_feature_flag_enabled!(context, Some(FeatureFlag::BN254_MODULI_NATIVE));
+    context.charge(ALGEBRA_ARK_BN254_MODULUS)?;
+    Ok(smallvec![Value::u256(u256_from_lendian(&BN254_R_LENDIAN))])
+}
+
+fn bn254_fq_modulus_internal(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    _args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    abort_unless_feature_flag_enabled!(context, Some(FeatureFlag::BN254_MODULI_NATIVE));
+    context.charge(ALGEBRA_ARK_BN254_MODULUS)?;
+    Ok(smallvec![Value::u256(u256_from_lendian(&BN254_Q_LENDIAN))])
+}
+
+/// Encode a `u256` as a canonical `Fr` element.
+///
+/// The value is parsed with ark, which rejects values not less than the modulus,
+/// then serialized back so that the output is exactly what `FormatFrLsb`/`FormatFrMsb` would produce.
+fn bn254_fr_to_bytes_internal(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    abort_unless_feature_flag_enabled!(context, Some(FeatureFlag::BN254_FR_BYTES_NATIVE));
+    let big_endian = safely_pop_arg!(args, bool);
+    let x = safely_pop_arg!(args, U256);
+    context.charge(ALGEBRA_ARK_BN254_FR_DESER + ALGEBRA_ARK_BN254_FR_SERIALIZE)?;
+    let element =
+        ark_bn254::Fr::deserialize_uncompressed(x.to_le_bytes().as_slice()).map_err(|_| {
+            SafeNativeError::Abort {
+                abort_code: MOVE_ABORT_CODE_NON_CANONICAL_ELEMENT,
+            }
+        })?;
+    let mut buf = Vec::with_capacity(32);
+    element
+        .serialize_uncompressed(&mut buf)
+        .map_err(|_e| abort_invariant_violated())?;
+    if big_endian {
+        buf.reverse();
+    }
+    Ok(smallvec![Value::vector_u8(buf)])
+}
+
+/// Decode a canonical 32-byte `Fr` encoding into a `u256`, returning `(false, 0)` if it is not one.
+fn bn254_fr_from_bytes_internal(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    abort_unless_feature_flag_enabled!(context, Some(FeatureFlag::BN254_FR_BYTES_NATIVE));
+    let big_endian = safely_pop_arg!(args, bool);
+    let mut bytes = safely_pop_arg!(args, Vec<u8>);
+    // Exit early if the size is incorrect, as in `deserialize_internal`.
+    if bytes.len() != 32 {
+        return Ok(smallvec![Value::bool(false), Value::u256(U256::zero())]);
+    }
+    context.charge(ALGEBRA_ARK_BN254_FR_DESER)?;
+    if big_endian {
+        bytes.reverse();
+    }
+    match ark_bn254::Fr::deserialize_uncompressed(bytes.as_slice()) {
+        Ok(_) => Ok(smallvec![
+            Value::bool(true),
+            Value::u256(u256_from_lendian(&bytes))
+        ]),
+        Err(_) => Ok(smallvec![Value::bool(false), Value::u256(U256::zero())]),
+    }
+}
+
+/// Derive an `Fr` element from a transcript as `SHA2-512(DST || bytes) mod r`.
+///
+/// The 64-byte digest is read as a big-endian integer. Reducing 512 bits modulo the 254-bit $r$
+/// leaves a statistical distance from uniform of about $2^{-258}$.
+fn bn254_fr_from_transcript_internal(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    abort_unless_feature_flag_enabled!(context, Some(FeatureFlag::BN254_FR_FROM_TRANSCRIPT_NATIVE));
+    let bytes = safely_pop_arg!(args, Vec<u8>);
+    context.charge(
+        ALGEBRA_ARK_BN254_FR_FROM_TRANSCRIPT_BASE
+            + ALGEBRA_ARK_BN254_FR_FROM_TRANSCRIPT_PER_BYTE * NumBytes::new(bytes.len() as u64),
+    )?;
+    let mut hasher = Sha512::new();
+    hasher.update(FR_FROM_TRANSCRIPT_DST);
+    hasher.update(&bytes);
+    let digest = hasher.finalize();
+    let scalar = ark_bn254::Fr::from_be_bytes_mod_order(digest.as_slice());
+    Ok(smallvec![Value::u256(u256_from_lendian(
+        &scalar.into_bigint().to_bytes_le()
+    ))])
+}
+
+pub fn make_all(
+    builder: &SafeNativeBuilder,
+) -> impl Iterator<Item = (String, NativeFunction)> + '_ {
+    let natives = [
+        (
+            "bn254_fr_modulus_internal",
+            bn254_fr_modulus_internal as RawSafeNative,
+        ),
+        ("bn254_fq_modulus_internal", bn254_fq_modulus_internal),
+        ("bn254_fr_to_bytes_internal", bn254_fr_to_bytes_internal),
+        ("bn254_fr_from_bytes_internal", bn254_fr_from_bytes_internal),
+        (
+            "bn254_fr_from_transcript_internal",
+            bn254_fr_from_transcript_internal,
+        ),
+    ];
+
+    builder.make_named_natives(natives)
+}
@@ -31,6 +31,10 @@ fn feature_flag_of_casting(
         (Some(Structure::BN254Fq12), Some(Structure::BN254Gt)) => {
             Some(FeatureFlag::BN254_STRUCTURES)
         },
+        (Some(Structure::BN254Fq), Some(Structure::GrumpkinFr))
+        | (Some(Structure::GrumpkinFr), Some(Structure::BN254Fq)) => {
+            Some(FeatureFlag::GRUMPKIN_STRUCTURES)
+        },
         _ => None,
     }
 }
@@ -72,6 +76,12 @@ pub fn downcast_internal(
                 Ok(smallvec![Value::bool(false), Value::u64(handle as u64)])
             }
         },
+        // Grumpkin Fr and BN254 Fq are both stored as `ark_bn254::Fq`, so the cast always succeeds.
+        (Some(Structure::BN254Fq), Some(Structure::GrumpkinFr))
+        | (Some(Structure::GrumpkinFr), Some(Structure::BN254Fq)) => {
+            let handle = safely_pop_arg!(args, u64);
+            Ok(smallvec![Value::bool(true), Value::u64(handle)])
+        },
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
@@ -96,6 +106,11 @@ pub fn upcast_internal(
             let handle = safely_pop_arg!(args, u64);
             Ok(smallvec![Value::u64(handle)])
         },
+        (Some(Structure::GrumpkinFr), Some(Structure::BN254Fq))
+        | (Some(Structure::BN254Fq), Some(Structure::GrumpkinFr)) => {
+            let handle = safely_pop_arg!(args, u64);
+            Ok(smallvec![Value::u64(handle)])
+        },
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
@@ -4,10 +4,12 @@
 use crate::{
     abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
     natives::cryptography::algebra::{
-        feature_flag_from_structure, AlgebraContext, Structure, BLS12381_GT_GENERATOR,
-        BLS12381_Q12_LENDIAN, BLS12381_R_LENDIAN, BN254_GT_GENERATOR, BN254_Q12_LENDIAN,
-        BN254_Q_LENDIAN, BN254_R_LENDIAN, E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES,
-        MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        feature_flag_from_structure, grumpkin, AlgebraContext, Structure, BLS12381_GT_GENERATOR,
+        BLS12381_Q12_LENDIAN, BLS12381_Q2_LENDIAN, BLS12381_Q_LENDIAN, BLS12381_R_LENDIAN,
+        BN254_GT_GENERATOR, BN254_Q12_LENDIAN, BN254_Q_LENDIAN, BN254_R_LENDIAN,
+        E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        PALLAS_Q_LENDIAN, PALLAS_R_LENDIAN, SECP256K1_Q_LENDIAN, SECP256K1_R_LENDIAN,
+        SECP256R1_Q_LENDIAN, SECP256R1_R_LENDIAN,
     },
     store_element, structure_from_ty_arg,
 };
@@ -43,6 +45,18 @@ pub fn zero_internal(
             zero,
             ALGEBRA_ARK_BLS12_381_FR_ZERO
         ),
+        Some(Structure::BLS12381Fq) => ark_constant_op_internal!(
+            context,
+            ark_bls12_381::Fq,
+            zero,
+            ALGEBRA_ARK_BLS12_381_FQ_ZERO
+        ),
+        Some(Structure::BLS12381Fq2) => ark_constant_op_internal!(
+            context,
+            ark_bls12_381::Fq2,
+            zero,
+            ALGEBRA_ARK_BLS12_381_FQ2_ZERO
+        ),
         Some(Structure::BLS12381Fq12) => ark_constant_op_internal!(
             context,
             ark_bls12_381::Fq12,
@@ -91,6 +105,78 @@ pub fn zero_internal(
         Some(Structure::BN254Gt) => {
             ark_constant_op_internal!(context, ark_bn254::Fq12, one, ALGEBRA_ARK_BN254_FQ12_ONE)
         },
+        Some(Structure::Secp256k1Fr) => ark_constant_op_internal!(
+            context,
+            ark_secp256k1::Fr,
+            zero,
+            ALGEBRA_ARK_SECP256K1_FR_ZERO
+        ),
+        Some(Structure::Secp256k1Fq) => ark_constant_op_internal!(
+            context,
+            ark_secp256k1::Fq,
+            zero,
+            ALGEBRA_ARK_SECP256K1_FQ_ZERO
+        ),
+        Some(Structure::Secp256k1G1) => ark_constant_op_internal!(
+            context,
+            ark_secp256k1::Projective,
+            zero,
+            ALGEBRA_ARK_SECP256K1_G1_PROJ_INFINITY
+        ),
+        Some(Structure::Secp256r1Fr) => ark_constant_op_internal!(
+            context,
+            ark_secp256r1::Fr,
+            zero,
+            ALGEBRA_ARK_SECP256R1_FR_ZERO
+        ),
+        Some(Structure::Secp256r1Fq) => ark_constant_op_internal!(
+            context,
+            ark_secp256r1::Fq,
+            zero,
+            ALGEBRA_ARK_SECP256R1_FQ_ZERO
+        ),
+        Some(Structure::Secp256r1G1) => ark_constant_op_internal!(
+            context,
+            ark_secp256r1::Projective,
+            zero,
+            ALGEBRA_ARK_SECP256R1_G1_PROJ_INFINITY
+        ),
+        Some(Structure::GrumpkinFr) => {
+            ark_constant_op_internal!(context, grumpkin::Fr, zero, ALGEBRA_ARK_GRUMPKIN_FR_ZERO)
+        },
+        Some(Structure::GrumpkinFq) => {
+            ark_constant_op_internal!(context, grumpkin::Fq, zero, ALGEBRA_ARK_GRUMPKIN_FQ_ZERO)
+        },
+        Some(Structure::GrumpkinG1) => ark_constant_op_internal!(
+            context,
+            grumpkin::Projective,
+            zero,
+            ALGEBRA_ARK_GRUMPKIN_G1_PROJ_INFINITY
+        ),
+        Some(Structure::PallasFr) => {
+            ark_constant_op_internal!(context, ark_pallas::Fr, zero, ALGEBRA_ARK_PALLAS_FR_ZERO)
+        },
+        Some(Structure::PallasFq) => {
+            ark_constant_op_internal!(context, ark_pallas::Fq, zero, ALGEBRA_ARK_PALLAS_FQ_ZERO)
+        },
+        Some(Structure::PallasG1) => ark_constant_op_internal!(
+            context,
+            ark_pallas::Projective,
+            zero,
+            ALGEBRA_ARK_PALLAS_G1_PROJ_INFINITY
+        ),
+        Some(Structure::VestaFr) => {
+            ark_constant_op_internal!(context, ark_vesta::Fr, zero, ALGEBRA_ARK_VESTA_FR_ZERO)
+        },
+        Some(Structure::VestaFq) => {
+            ark_constant_op_internal!(context, ark_vesta::Fq, zero, ALGEBRA_ARK_VESTA_FQ_ZERO)
+        },
+        Some(Structure::VestaG1) => ark_constant_op_internal!(
+            context,
+            ark_vesta::Projective,
+            zero,
+            ALGEBRA_ARK_VESTA_G1_PROJ_INFINITY
+        ),
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
@@ -111,6 +197,18 @@ pub fn one_internal(
             one,
             ALGEBRA_ARK_BLS12_381_FR_ONE
         ),
+        Some(Structure::BLS12381Fq) => ark_constant_op_internal!(
+            context,
+            ark_bls12_381::Fq,
+            one,
+            ALGEBRA_ARK_BLS12_381_FQ_ONE
+        ),
+        Some(Structure::BLS12381Fq2) => ark_constant_op_internal!(
+            context,
+            ark_bls12_381::Fq2,
+            one,
+            ALGEBRA_ARK_BLS12_381_FQ2_ONE
+        ),
         Some(Structure::BLS12381Fq12) => ark_constant_op_internal!(
             context,
             ark_bls12_381::Fq12,
@@ -162,6 +260,86 @@ pub fn one_internal(
             let handle = store_element!(context, element)?;
             Ok(smallvec![Value::u64(handle as u64)])
         },
+        Some(Structure::Secp256k1Fr) => {
+            ark_constant_op_internal!(
+                context,
+                ark_secp256k1::Fr,
+                one,
+                ALGEBRA_ARK_SECP256K1_FR_ONE
+            )
+        },
+        Some(Structure::Secp256k1Fq) => {
+            ark_constant_op_internal!(
+                context,
+                ark_secp256k1::Fq,
+                one,
+                ALGEBRA_ARK_SECP256K1_FQ_ONE
+            )
+        },
+        Some(Structure::Secp256k1G1) => ark_constant_op_internal!(
+            context,
+            ark_secp256k1::Projective,
+            generator,
+            ALGEBRA_ARK_SECP256K1_G1_PROJ_GENERATOR
+        ),
+        Some(Structure::Secp256r1Fr) => {
+            ark_constant_op_internal!(
+                context,
+                ark_secp256r1::Fr,
+                one,
+                ALGEBRA_ARK_SECP256R1_FR_ONE
+            )
+        },
+        Some(Structure::Secp256r1Fq) => {
+            ark_constant_op_internal!(
+                context,
+                ark_secp256r1::Fq,
+                one,
+                ALGEBRA_ARK_SECP256R1_FQ_ONE
+            )
+        },
+        Some(Structure::Secp256r1G1) => ark_constant_op_internal!(
+            context,
+            ark_secp256r1::Projective,
+            generator,
+            ALGEBRA_ARK_SECP256R1_G1_PROJ_GENERATOR
+        ),
+        Some(Structure::GrumpkinFr) => {
+            ark_constant_op_internal!(context, grumpkin::Fr, one, ALGEBRA_ARK_GRUMPKIN_FR_ONE)
+        },
+        Some(Structure::GrumpkinFq) => {
+            ark_constant_op_internal!(context, grumpkin::Fq, one, ALGEBRA_ARK_GRUMPKIN_FQ_ONE)
+        },
+        Some(Structure::GrumpkinG1) => ark_constant_op_internal!(
+            context,
+            grumpkin::Projective,
+            generator,
+            ALGEBRA_ARK_GRUMPKIN_G1_PROJ_GENERATOR
+        ),
+        Some(Structure::PallasFr) => {
+            ark_constant_op_internal!(context, ark_pallas::Fr, one, ALGEBRA_ARK_PALLAS_FR_ONE)
+        },
+        Some(Structure::PallasFq) => {
+            ark_constant_op_internal!(context, ark_pallas::Fq, one, ALGEBRA_ARK_PALLAS_FQ_ONE)
+        },
+        Some(Structure::PallasG1) => ark_constant_op_internal!(
+            context,
+            ark_pallas::Projective,
+            generator,
+            ALGEBRA_ARK_PALLAS_G1_PROJ_GENERATOR
+        ),
+        Some(Structure::VestaFr) => {
+            ark_constant_op_internal!(context, ark_vesta::Fr, one, ALGEBRA_ARK_VESTA_FR_ONE)
+        },
+        Some(Structure::VestaFq) => {
+            ark_constant_op_internal!(context, ark_vesta::Fq, one, ALGEBRA_ARK_VESTA_FQ_ONE)
+        },
+        Some(Structure::VestaG1) => ark_constant_op_internal!(
+            context,
+            ark_vesta::Projective,
+            generator,
+            ALGEBRA_ARK_VESTA_G1_PROJ_GENERATOR
+        ),
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
@@ -183,6 +361,10 @@ pub fn order_internal(
         | Some(Structure::BLS12381Gt) => {
             Ok(smallvec![Value::vector_u8(BLS12381_R_LENDIAN.clone())])
         },
+        Some(Structure::BLS12381Fq) => Ok(smallvec![Value::vector_u8(BLS12381_Q_LENDIAN.clone())]),
+        Some(Structure::BLS12381Fq2) => {
+            Ok(smallvec![Value::vector_u8(BLS12381_Q2_LENDIAN.clone())])
+        },
         Some(Structure::BLS12381Fq12) => {
             Ok(smallvec![Value::vector_u8(BLS12381_Q12_LENDIAN.clone())])
         },
@@ -192,6 +374,30 @@ pub fn order_internal(
         | Some(Structure::BN254G2) => Ok(smallvec![Value::vector_u8(BN254_R_LENDIAN.clone())]),
         Some(Structure::BN254Fq) => Ok(smallvec![Value::vector_u8(BN254_Q_LENDIAN.clone())]),
         Some(Structure::BN254Fq12) => Ok(smallvec![Value::vector_u8(BN254_Q12_LENDIAN.clone())]),
+        Some(Structure::Secp256k1Fr) | Some(Structure::Secp256k1G1) => {
+            Ok(smallvec![Value::vector_u8(SECP256K1_R_LENDIAN.clone())])
+        },
+        Some(Structure::Secp256k1Fq) => {
+            Ok(smallvec![Value::vector_u8(SECP256K1_Q_LENDIAN.clone())])
+        },
+        Some(Structure::Secp256r1Fr) | Some(Structure::Secp256r1G1) => {
+            Ok(smallvec![Value::vector_u8(SECP256R1_R_LENDIAN.clone())])
+        },
+        Some(Structure::Secp256r1Fq) => {
+            Ok(smallvec![Value::vector_u8(SECP256R1_Q_LENDIAN.clone())])
+        },
+        Some(Structure::GrumpkinFr) | Some(Structure::GrumpkinG1) => {
+            Ok(smallvec![Value::vector_u8(BN254_Q_LENDIAN.clone())])
+        },
+        Some(Structure::GrumpkinFq) => Ok(smallvec![Value::vector_u8(BN254_R_LENDIAN.clone())]),
+        Some(Structure::PallasFr) | Some(Structure::PallasG1) => {
+            Ok(smallvec![Value::vector_u8(PALLAS_R_LENDIAN.clone())])
+        },
+        Some(Structure::PallasFq) => Ok(smallvec![Value::vector_u8(PALLAS_Q_LENDIAN.clone())]),
+        Some(Structure::VestaFr) | Some(Structure::VestaG1) => {
+            Ok(smallvec![Value::vector_u8(PALLAS_Q_LENDIAN.clone())])
+        },
+        Some(Structure::VestaFq) => Ok(smallvec![Value::vector_u8(PALLAS_R_LENDIAN.clone())]),
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
@@ -4,7 +4,7 @@
 use crate::{
     abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
     natives::cryptography::algebra::{
-        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, Structure,
+        abort_invariant_violated, feature_flag_from_structure, grumpkin, AlgebraContext, Structure,
         MOVE_ABORT_CODE_NOT_IMPLEMENTED,
     },
     safe_borrow_element, structure_from_ty_arg,
@@ -13,6 +13,8 @@ use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
 use aptos_native_interface::{
     safely_pop_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
 };
+use aptos_types::on_chain_config::FeatureFlag;
+use ark_ff::{One, Zero};
 use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
 use smallvec::{smallvec, SmallVec};
 use std::collections::VecDeque;
@@ -44,6 +46,18 @@ pub fn eq_internal(
             ark_bls12_381::Fr,
             ALGEBRA_ARK_BLS12_381_FR_EQ
         ),
+        Some(Structure::BLS12381Fq) => ark_eq_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq,
+            ALGEBRA_ARK_BLS12_381_FQ_EQ
+        ),
+        Some(Structure::BLS12381Fq2) => ark_eq_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq2,
+            ALGEBRA_ARK_BLS12_381_FQ2_EQ
+        ),
         Some(Structure::BLS12381Fq12) => ark_eq_internal!(
             context,
             args,
@@ -96,6 +110,204 @@ pub fn eq_internal(
         Some(Structure::BN254Gt) => {
             ark_eq_internal!(context, args, ark_bn254::Fq12, ALGEBRA_ARK_BN254_FQ12_EQ)
         },
+        Some(Structure::Secp256k1Fr) => {
+            ark_eq_internal!(
+                context,
+                args,
+                ark_secp256k1::Fr,
+                ALGEBRA_ARK_SECP256K1_FR_EQ
+            )
+        },
+        Some(Structure::Secp256k1Fq) => {
+            ark_eq_internal!(
+                context,
+                args,
+                ark_secp256k1::Fq,
+                ALGEBRA_ARK_SECP256K1_FQ_EQ
+            )
+        },
+        Some(Structure::Secp256k1G1) => ark_eq_internal!(
+            context,
+            args,
+            ark_secp256k1::Projective,
+            ALGEBRA_ARK_SECP256K1_G1_PROJ_EQ
+        ),
+        Some(Structure::Secp256r1Fr) => {
+            ark_eq_internal!(
+                context,
+                args,
+                ark_secp256r1::Fr,
+                ALGEBRA_ARK_SECP256R1_FR_EQ
+            )
+        },
+        Some(Structure::Secp256r1Fq) => {
+            ark_eq_internal!(
+                context,
+                args,
+                ark_secp256r1::Fq,
+                ALGEBRA_ARK_SECP256R1_FQ_EQ
+            )
+        },
+        Some(Structure::Secp256r1G1) => ark_eq_internal!(
+            context,
+            args,
+            ark_secp256r1::Projective,
+            ALGEBRA_ARK_SECP256R1_G1_PROJ_EQ
+        ),
+        Some(Structure::GrumpkinFr) => {
+            ark_eq_internal!(context, args, grumpkin::Fr, ALGEBRA_ARK_GRUMPKIN_FR_EQ)
+        },
+        Some(Structure::GrumpkinFq) => {
+            ark_eq_internal!(context, args, grumpkin::Fq, ALGEBRA_ARK_GRUMPKIN_FQ_EQ)
+        },
+        Some(Structure::GrumpkinG1) => {
+            ark_eq_internal!(
+                context,
+                args,
+                grumpkin::Projective,
+                ALGEBRA_ARK_GRUMPKIN_G1_PROJ_EQ
+            )
+        },
+        Some(Structure::PallasFr) => {
+            ark_eq_internal!(context, args, ark_pallas::Fr, ALGEBRA_ARK_PALLAS_FR_EQ)
+        },
+        Some(Structure::PallasFq) => {
+            ark_eq_internal!(context, args, ark_pallas::Fq, ALGEBRA_ARK_PALLAS_FQ_EQ)
+        },
+        Some(Structure::PallasG1) => {
+            ark_eq_internal!(
+                context,
+                args,
+                ark_pallas::Projective,
+                ALGEBRA_ARK_PALLAS_G1_PROJ_EQ
+            )
+        },
+        Some(Structure::VestaFr) => {
+            ark_eq_internal!(context, args, ark_vesta::Fr, ALGEBRA_ARK_VESTA_FR_EQ)
+        },
+        Some(Structure::VestaFq) => {
+            ark_eq_internal!(context, args, ark_vesta::Fq, ALGEBRA_ARK_VESTA_FQ_EQ)
+        },
+        Some(Structure::VestaG1) => {
+            ark_eq_internal!(
+                context,
+                args,
+                ark_vesta::Projective,
+                ALGEBRA_ARK_VESTA_G1_PROJ_EQ
+            )
+        },
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
+
+macro_rules! ark_is_constant_internal {
+    ($context:ident, $args:ident, $ark_typ:ty, $op:ident, $gas:expr) => {{
+        let handle = safely_pop_arg!($args, u64) as usize;
+        safe_borrow_element!($context, handle, $ark_typ, element_ptr, element);
+        $context.charge($gas)?;
+        let result = element.$op();
+        Ok(smallvec![Value::bool(result)])
+    }};
+}
+
+/// Check whether an element is what `zero_internal` returns for its structure,
+/// i.e., the additive identity of a field or the identity of a group (`1` for `Gt`).
+pub fn is_zero_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(1, ty_args.len());
+    abort_unless_feature_flag_enabled!(context, Some(FeatureFlag::ALGEBRA_IS_ZERO_IS_ONE_NATIVES));
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    abort_unless_arithmetics_enabled_for_structure!(context, structure_opt);
+    match structure_opt {
+        Some(Structure::BN254Fr) => ark_is_constant_internal!(
+            context,
+            args,
+            ark_bn254::Fr,
+            is_zero,
+            ALGEBRA_ARK_BN254_FR_EQ
+        ),
+        Some(Structure::BN254Fq) => ark_is_constant_internal!(
+            context,
+            args,
+            ark_bn254::Fq,
+            is_zero,
+            ALGEBRA_ARK_BN254_FQ_EQ
+        ),
+        Some(Structure::BN254Fq12) => ark_is_constant_internal!(
+            context,
+            args,
+            ark_bn254::Fq12,
+            is_zero,
+            ALGEBRA_ARK_BN254_FQ12_EQ
+        ),
+        Some(Structure::BN254G1) => ark_is_constant_internal!(
+            context,
+            args,
+            ark_bn254::G1Projective,
+            is_zero,
+            ALGEBRA_ARK_BN254_G1_PROJ_EQ
+        ),
+        Some(Structure::BN254G2) => ark_is_constant_internal!(
+            context,
+            args,
+            ark_bn254::G2Projective,
+            is_zero,
+            ALGEBRA_ARK_BN254_G2_PROJ_EQ
+        ),
+        Some(Structure::BN254Gt) => ark_is_constant_internal!(
+            context,
+            args,
+            ark_bn254::Fq12,
+            is_one,
+            ALGEBRA_ARK_BN254_FQ12_EQ
+        ),
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
+
+/// Check whether a field element is the multiplicative identity, i.e., what `one_internal` returns.
+pub fn is_one_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(1, ty_args.len());
+    abort_unless_feature_flag_enabled!(context, Some(FeatureFlag::ALGEBRA_IS_ZERO_IS_ONE_NATIVES));
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    abort_unless_arithmetics_enabled_for_structure!(context, structure_opt);
+    match structure_opt {
+        Some(Structure::BN254Fr) => {
+            ark_is_constant_internal!(
+                context,
+                args,
+                ark_bn254::Fr,
+                is_one,
+                ALGEBRA_ARK_BN254_FR_EQ
+            )
+        },
+        Some(Structure::BN254Fq) => {
+            ark_is_constant_internal!(
+                context,
+                args,
+                ark_bn254::Fq,
+                is_one,
+                ALGEBRA_ARK_BN254_FQ_EQ
+            )
+        },
+        Some(Structure::BN254Fq12) => ark_is_constant_internal!(
+            context,
+            args,
+            ark_bn254::Fq12,
+            is_one,
+            ALGEBRA_ARK_BN254_FQ12_EQ
+        ),
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
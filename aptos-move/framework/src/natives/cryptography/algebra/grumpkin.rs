@@ -0,0 +1,61 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! The Grumpkin curve `y^2 = x^3 - 17`, defined over the BN254 scalar field.
+//!
+//! Its group order equals the BN254 base field modulus, so Grumpkin and BN254 form a cycle:
+//! the Grumpkin scalar field is `ark_bn254::Fq` and the Grumpkin base field is `ark_bn254::Fr`.
+//! There is no arkworks 0.4 release of Grumpkin, so the curve parameters are defined here.
+
+use ark_ec::{
+    models::CurveConfig,
+    short_weierstrass::{self as sw, SWCurveConfig},
+};
+use ark_ff::{Field, MontFp, Zero};
+
+pub type Fq = ark_bn254::Fr;
+pub type Fr = ark_bn254::Fq;
+pub type Affine = sw::Affine<Config>;
+pub type Projective = sw::Projective<Config>;
+
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct Config;
+
+impl CurveConfig for Config {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    /// The curve has prime order.
+    const COFACTOR: &'static [u64] = &[0x1];
+    const COFACTOR_INV: Fr = Fr::ONE;
+}
+
+impl SWCurveConfig for Config {
+    const COEFF_A: Fq = Fq::ZERO;
+    const COEFF_B: Fq = MontFp!("-17");
+    const GENERATOR: Affine = Affine::new_unchecked(GENERATOR_X, GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+/// The generator `(1, sqrt(-16))` used by Aztec's barretenberg and noir.
+pub const GENERATOR_X: Fq = Fq::ONE;
+pub const GENERATOR_Y: Fq = MontFp!("17631683881184975370165255887551781615748388533673675138860");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ec::Group;
+    use ark_ff::PrimeField;
+
+    #[test]
+    fn generator_is_valid() {
+        let generator = Affine::new(GENERATOR_X, GENERATOR_Y);
+        assert!(generator.is_on_curve());
+        assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+        assert!(Projective::generator().mul_bigint(Fr::MODULUS).is_zero());
+    }
+}
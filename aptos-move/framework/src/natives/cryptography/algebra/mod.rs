@@ -1,20 +1,23 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-#[cfg(feature = "testing")]
-use crate::natives::cryptography::algebra::rand::rand_insecure_internal;
 use crate::natives::cryptography::algebra::{
     arithmetics::{
-        add::add_internal, double::double_internal, mul::mul_internal, neg::neg_internal,
-        sqr::sqr_internal, sub::sub_internal,
+        add::add_internal, cyclotomic_pow::fq12_cyclotomic_pow_internal, double::double_internal,
+        mul::mul_internal, neg::neg_internal, pow::pow_u64_internal, sqr::sqr_internal,
+        sub::sub_internal,
     },
     casting::{downcast_internal, upcast_internal},
     constants::{one_internal, order_internal, zero_internal},
-    eq::eq_internal,
+    eq::{eq_internal, is_one_internal, is_zero_internal},
     hash_to_structure::hash_to_internal,
     new::from_u64_internal,
     pairing::{multi_pairing_internal, pairing_internal},
-    serialization::{deserialize_internal, serialize_internal},
+    serialization::{deserialize_checked_internal, deserialize_internal, serialize_internal},
+};
+#[cfg(feature = "testing")]
+use crate::natives::cryptography::algebra::{
+    new::point_from_coordinates_internal, rand::rand_insecure_internal,
 };
 use aptos_native_interface::{RawSafeNative, SafeNativeBuilder};
 use aptos_types::on_chain_config::FeatureFlag;
@@ -29,23 +32,43 @@ use better_any::{Tid, TidAble};
 use move_binary_format::errors::PartialVMError;
 use move_core_types::{language_storage::TypeTag, vm_status::StatusCode};
 use move_vm_runtime::native_functions::NativeFunction;
+use move_vm_types::loaded_data::runtime_types::Type;
 use once_cell::sync::Lazy;
-use std::{any::Any, hash::Hash, rc::Rc};
+use std::{any::Any, collections::HashMap, hash::Hash, rc::Rc};
 
 pub mod arithmetics;
+pub mod bn254;
 pub mod casting;
 pub mod constants;
 pub mod eq;
+pub mod grumpkin;
 pub mod hash_to_structure;
 pub mod new;
 pub mod pairing;
+pub mod pasta;
 #[cfg(feature = "testing")]
 pub mod rand;
+pub mod sec1;
 pub mod serialization;
 
 /// Equivalent to `std::error::invalid_argument(0)` in Move.
 const MOVE_ABORT_CODE_INPUT_VECTOR_SIZES_NOT_MATCHING: u64 = 0x01_0002;
 
+/// Equivalent to `std::error::invalid_argument(4)` in Move.
+const MOVE_ABORT_CODE_MALFORMED_POINT_ENCODING: u64 = 0x01_0004;
+
+/// Equivalent to `std::error::invalid_argument(5)` in Move.
+const MOVE_ABORT_CODE_POINT_NOT_ON_CURVE: u64 = 0x01_0005;
+
+/// Equivalent to `std::error::invalid_argument(6)` in Move.
+const MOVE_ABORT_CODE_POINT_NOT_IN_SUBGROUP: u64 = 0x01_0006;
+
+/// Equivalent to `std::error::invalid_argument(7)` in Move.
+const MOVE_ABORT_CODE_NON_CANONICAL_ELEMENT: u64 = 0x01_0007;
+
+/// Equivalent to `std::error::invalid_argument(8)` in Move.
+const MOVE_ABORT_CODE_NOT_IN_CYCLOTOMIC_SUBGROUP: u64 = 0x01_0008;
+
 /// Equivalent to `std::error::not_implemented(0)` in Move.
 const MOVE_ABORT_CODE_NOT_IMPLEMENTED: u64 = 0x0C_0001;
 
@@ -57,6 +80,8 @@ pub enum Structure {
     BLS12381G2,
     BLS12381Gt,
     BLS12381Fr,
+    BLS12381Fq,
+    BLS12381Fq2,
 
     BN254Fr,
     BN254Fq,
@@ -64,6 +89,26 @@ pub enum Structure {
     BN254G1,
     BN254G2,
     BN254Gt,
+
+    Secp256k1Fr,
+    Secp256k1Fq,
+    Secp256k1G1,
+
+    Secp256r1Fr,
+    Secp256r1Fq,
+    Secp256r1G1,
+
+    GrumpkinFr,
+    GrumpkinFq,
+    GrumpkinG1,
+
+    PallasFr,
+    PallasFq,
+    PallasG1,
+
+    VestaFr,
+    VestaFq,
+    VestaG1,
 }
 
 impl TryFrom<TypeTag> for Structure {
@@ -76,6 +121,8 @@ impl TryFrom<TypeTag> for Structure {
             "0x1::bls12381_algebra::G1" => Ok(Structure::BLS12381G1),
             "0x1::bls12381_algebra::G2" => Ok(Structure::BLS12381G2),
             "0x1::bls12381_algebra::Gt" => Ok(Structure::BLS12381Gt),
+            "0x1::bls12381_algebra::Fq" => Ok(Structure::BLS12381Fq),
+            "0x1::bls12381_algebra::Fq2" => Ok(Structure::BLS12381Fq2),
 
             "0x1::bn254_algebra::Fr" => Ok(Self::BN254Fr),
             "0x1::bn254_algebra::Fq" => Ok(Self::BN254Fq),
@@ -83,16 +130,61 @@ impl TryFrom<TypeTag> for Structure {
             "0x1::bn254_algebra::G1" => Ok(Self::BN254G1),
             "0x1::bn254_algebra::G2" => Ok(Self::BN254G2),
             "0x1::bn254_algebra::Gt" => Ok(Self::BN254Gt),
+
+            "0x1::secp256k1_algebra::Fr" => Ok(Self::Secp256k1Fr),
+            "0x1::secp256k1_algebra::Fq" => Ok(Self::Secp256k1Fq),
+            "0x1::secp256k1_algebra::G1" => Ok(Self::Secp256k1G1),
+
+            "0x1::secp256r1_algebra::Fr" => Ok(Self::Secp256r1Fr),
+            "0x1::secp256r1_algebra::Fq" => Ok(Self::Secp256r1Fq),
+            "0x1::secp256r1_algebra::G1" => Ok(Self::Secp256r1G1),
+
+            "0x1::grumpkin_algebra::Fr" => Ok(Self::GrumpkinFr),
+            "0x1::grumpkin_algebra::Fq" => Ok(Self::GrumpkinFq),
+            "0x1::grumpkin_algebra::G1" => Ok(Self::GrumpkinG1),
+
+            "0x1::pallas_algebra::Fr" => Ok(Self::PallasFr),
+            "0x1::pallas_algebra::Fq" => Ok(Self::PallasFq),
+            "0x1::pallas_algebra::G1" => Ok(Self::PallasG1),
+
+            "0x1::vesta_algebra::Fr" => Ok(Self::VestaFr),
+            "0x1::vesta_algebra::Fq" => Ok(Self::VestaFq),
+            "0x1::vesta_algebra::G1" => Ok(Self::VestaG1),
             _ => Err(()),
         }
     }
 }
 
+/// Resolve a type argument to the `Structure` it represents, if any.
+///
+/// Converting a `Type` to a `TypeTag` and matching its name is not cheap,
+/// so resolved structures are cached per session in the `AlgebraContext`, keyed by the `Type`.
+/// Only supported structures are cached, which bounds the cache by the number of `Structure` variants.
 #[macro_export]
 macro_rules! structure_from_ty_arg {
     ($context:expr, $typ:expr) => {{
-        let type_tag = $context.type_to_type_tag($typ)?;
-        Structure::try_from(type_tag).ok()
+        let typ: &move_vm_types::loaded_data::runtime_types::Type = $typ;
+        let cached = $context
+            .extensions()
+            .get::<$crate::natives::cryptography::algebra::AlgebraContext>()
+            .structures
+            .get(typ)
+            .copied();
+        match cached {
+            Some(structure) => Some(structure),
+            None => {
+                let type_tag = $context.type_to_type_tag(typ)?;
+                let structure_opt = Structure::try_from(type_tag).ok();
+                if let Some(structure) = structure_opt {
+                    $context
+                        .extensions_mut()
+                        .get_mut::<$crate::natives::cryptography::algebra::AlgebraContext>()
+                        .structures
+                        .insert(typ.clone(), structure);
+                }
+                structure_opt
+            },
+        }
     }};
 }
 
@@ -107,6 +199,9 @@ pub enum SerializationFormat {
     BLS12381Gt,
     BLS12381FrLsb,
     BLS12381FrMsb,
+    BLS12381FqLsb,
+    BLS12381FqMsb,
+    BLS12381Fq2LscLsb,
 
     BN254G1Compressed,
     BN254G1Uncompressed,
@@ -118,6 +213,41 @@ pub enum SerializationFormat {
     BN254FqLsb,
     BN254FqMsb,
     BN254Fq12LscLsb,
+
+    Secp256k1FrLsb,
+    Secp256k1FrMsb,
+    Secp256k1FqLsb,
+    Secp256k1FqMsb,
+    Secp256k1G1Compressed,
+    Secp256k1G1Uncompressed,
+
+    Secp256r1FrLsb,
+    Secp256r1FrMsb,
+    Secp256r1FqLsb,
+    Secp256r1FqMsb,
+    Secp256r1G1Compressed,
+    Secp256r1G1Uncompressed,
+
+    GrumpkinFrLsb,
+    GrumpkinFrMsb,
+    GrumpkinFqLsb,
+    GrumpkinFqMsb,
+    GrumpkinG1Compressed,
+    GrumpkinG1Uncompressed,
+
+    PallasFrLsb,
+    PallasFrMsb,
+    PallasFqLsb,
+    PallasFqMsb,
+    PallasG1Compressed,
+    PallasG1Uncompressed,
+
+    VestaFrLsb,
+    VestaFrMsb,
+    VestaFqLsb,
+    VestaFqMsb,
+    VestaG1Compressed,
+    VestaG1Uncompressed,
 }
 
 impl TryFrom<TypeTag> for SerializationFormat {
@@ -139,6 +269,9 @@ impl TryFrom<TypeTag> for SerializationFormat {
             "0x1::bls12381_algebra::FormatGt" => Ok(SerializationFormat::BLS12381Gt),
             "0x1::bls12381_algebra::FormatFrLsb" => Ok(SerializationFormat::BLS12381FrLsb),
             "0x1::bls12381_algebra::FormatFrMsb" => Ok(SerializationFormat::BLS12381FrMsb),
+            "0x1::bls12381_algebra::FormatFqLsb" => Ok(SerializationFormat::BLS12381FqLsb),
+            "0x1::bls12381_algebra::FormatFqMsb" => Ok(SerializationFormat::BLS12381FqMsb),
+            "0x1::bls12381_algebra::FormatFq2LscLsb" => Ok(SerializationFormat::BLS12381Fq2LscLsb),
 
             "0x1::bn254_algebra::FormatG1Uncompr" => Ok(Self::BN254G1Uncompressed),
             "0x1::bn254_algebra::FormatG1Compr" => Ok(Self::BN254G1Compressed),
@@ -150,6 +283,41 @@ impl TryFrom<TypeTag> for SerializationFormat {
             "0x1::bn254_algebra::FormatFqLsb" => Ok(Self::BN254FqLsb),
             "0x1::bn254_algebra::FormatFqMsb" => Ok(Self::BN254FqMsb),
             "0x1::bn254_algebra::FormatFq12LscLsb" => Ok(Self::BN254Fq12LscLsb),
+
+            "0x1::secp256k1_algebra::FormatFrLsb" => Ok(Self::Secp256k1FrLsb),
+            "0x1::secp256k1_algebra::FormatFrMsb" => Ok(Self::Secp256k1FrMsb),
+            "0x1::secp256k1_algebra::FormatFqLsb" => Ok(Self::Secp256k1FqLsb),
+            "0x1::secp256k1_algebra::FormatFqMsb" => Ok(Self::Secp256k1FqMsb),
+            "0x1::secp256k1_algebra::FormatG1Compr" => Ok(Self::Secp256k1G1Compressed),
+            "0x1::secp256k1_algebra::FormatG1Uncompr" => Ok(Self::Secp256k1G1Uncompressed),
+
+            "0x1::secp256r1_algebra::FormatFrLsb" => Ok(Self::Secp256r1FrLsb),
+            "0x1::secp256r1_algebra::FormatFrMsb" => Ok(Self::Secp256r1FrMsb),
+            "0x1::secp256r1_algebra::FormatFqLsb" => Ok(Self::Secp256r1FqLsb),
+            "0x1::secp256r1_algebra::FormatFqMsb" => Ok(Self::Secp256r1FqMsb),
+            "0x1::secp256r1_algebra::FormatG1Compr" => Ok(Self::Secp256r1G1Compressed),
+            "0x1::secp256r1_algebra::FormatG1Uncompr" => Ok(Self::Secp256r1G1Uncompressed),
+
+            "0x1::grumpkin_algebra::FormatFrLsb" => Ok(Self::GrumpkinFrLsb),
+            "0x1::grumpkin_algebra::FormatFrMsb" => Ok(Self::GrumpkinFrMsb),
+            "0x1::grumpkin_algebra::FormatFqLsb" => Ok(Self::GrumpkinFqLsb),
+            "0x1::grumpkin_algebra::FormatFqMsb" => Ok(Self::GrumpkinFqMsb),
+            "0x1::grumpkin_algebra::FormatG1Compr" => Ok(Self::GrumpkinG1Compressed),
+            "0x1::grumpkin_algebra::FormatG1Uncompr" => Ok(Self::GrumpkinG1Uncompressed),
+
+            "0x1::pallas_algebra::FormatFrLsb" => Ok(Self::PallasFrLsb),
+            "0x1::pallas_algebra::FormatFrMsb" => Ok(Self::PallasFrMsb),
+            "0x1::pallas_algebra::FormatFqLsb" => Ok(Self::PallasFqLsb),
+            "0x1::pallas_algebra::FormatFqMsb" => Ok(Self::PallasFqMsb),
+            "0x1::pallas_algebra::FormatG1Compr" => Ok(Self::PallasG1Compressed),
+            "0x1::pallas_algebra::FormatG1Uncompr" => Ok(Self::PallasG1Uncompressed),
+
+            "0x1::vesta_algebra::FormatFrLsb" => Ok(Self::VestaFrLsb),
+            "0x1::vesta_algebra::FormatFrMsb" => Ok(Self::VestaFrMsb),
+            "0x1::vesta_algebra::FormatFqLsb" => Ok(Self::VestaFqLsb),
+            "0x1::vesta_algebra::FormatFqMsb" => Ok(Self::VestaFqMsb),
+            "0x1::vesta_algebra::FormatG1Compr" => Ok(Self::VestaG1Compressed),
+            "0x1::vesta_algebra::FormatG1Uncompr" => Ok(Self::VestaG1Uncompressed),
             _ => Err(()),
         }
     }
@@ -188,6 +356,7 @@ const E_TOO_MUCH_MEMORY_USED: u64 = 0x09_0003;
 pub struct AlgebraContext {
     bytes_used: usize,
     objs: Vec<Rc<dyn Any>>,
+    structures: HashMap<Type, Structure>,
 }
 
 impl AlgebraContext {
@@ -195,6 +364,7 @@ impl AlgebraContext {
         Self {
             bytes_used: 0,
             objs: Vec::new(),
+            structures: HashMap::new(),
         }
     }
 }
@@ -237,6 +407,32 @@ macro_rules! store_element {
     }};
 }
 
+/// Store a short Weierstrass affine point as its projective form, like `store_element!`,
+/// but abort with `MOVE_ABORT_CODE_POINT_NOT_ON_CURVE` if the point is not on the curve,
+/// and with `MOVE_ABORT_CODE_POINT_NOT_IN_SUBGROUP` if it is not in the prime-order subgroup.
+///
+/// Only the test-only `point_from_coordinates_internal` uses it, so it is only compiled with the
+/// `testing` feature. The deserialization natives validate points themselves and return `(false, 0)`
+/// instead of aborting.
+#[cfg(feature = "testing")]
+#[macro_export]
+macro_rules! store_constructed_point {
+    ($context:expr, $point:expr) => {{
+        let point = $point;
+        if !point.is_on_curve() {
+            Err(SafeNativeError::Abort {
+                abort_code: MOVE_ABORT_CODE_POINT_NOT_ON_CURVE,
+            })
+        } else if !point.is_in_correct_subgroup_assuming_on_curve() {
+            Err(SafeNativeError::Abort {
+                abort_code: MOVE_ABORT_CODE_POINT_NOT_IN_SUBGROUP,
+            })
+        } else {
+            store_element!($context, ark_ec::AffineRepr::into_group(point))
+        }
+    }};
+}
+
 fn feature_flag_from_structure(structure_opt: Option<Structure>) -> Option<FeatureFlag> {
     match structure_opt {
         Some(Structure::BLS12381Fr)
@@ -244,12 +440,30 @@ fn feature_flag_from_structure(structure_opt: Option<Structure>) -> Option<Featu
         | Some(Structure::BLS12381G1)
         | Some(Structure::BLS12381G2)
         | Some(Structure::BLS12381Gt) => Some(FeatureFlag::BLS12_381_STRUCTURES),
+        Some(Structure::BLS12381Fq) | Some(Structure::BLS12381Fq2) => {
+            Some(FeatureFlag::BLS12_381_BASE_FIELD_STRUCTURES)
+        },
         Some(Structure::BN254Fr)
         | Some(Structure::BN254Fq)
         | Some(Structure::BN254Fq12)
         | Some(Structure::BN254G1)
         | Some(Structure::BN254G2)
         | Some(Structure::BN254Gt) => Some(FeatureFlag::BN254_STRUCTURES),
+        Some(Structure::Secp256k1Fr)
+        | Some(Structure::Secp256k1Fq)
+        | Some(Structure::Secp256k1G1)
+        | Some(Structure::Secp256r1Fr)
+        | Some(Structure::Secp256r1Fq)
+        | Some(Structure::Secp256r1G1) => Some(FeatureFlag::SECP256K1_SECP256R1_STRUCTURES),
+        Some(Structure::GrumpkinFr) | Some(Structure::GrumpkinFq) | Some(Structure::GrumpkinG1) => {
+            Some(FeatureFlag::GRUMPKIN_STRUCTURES)
+        },
+        Some(Structure::PallasFr)
+        | Some(Structure::PallasFq)
+        | Some(Structure::PallasG1)
+        | Some(Structure::VestaFr)
+        | Some(Structure::VestaFq)
+        | Some(Structure::VestaG1) => Some(FeatureFlag::PASTA_STRUCTURES),
         _ => None,
     }
 }
@@ -299,6 +513,11 @@ static BLS12381_R_SCALAR: Lazy<ark_ff::BigInteger256> = Lazy::new(|| {
 static BLS12381_Q12_LENDIAN: Lazy<Vec<u8>> = Lazy::new(|| {
     hex::decode("1175f55da544c7625f8ccb1360e2b1d3ca40747811c8f5ed04440afe232b476c0215676aec05f2a44ac2da6b6d1b7cff075e7b2a587e0aab601a8d3db4f0d29906e5e4d0d78119f396d5a59f0f8d1ca8bca62540be6ab9c12d0ca00de1f311f106278d000e55a393c9766a74e0d08a298450f60d7e666575e3354bf14b8731f4e721c0c180a5ed55c2f8f51f815baecbf96b5fc717eb58ac161a27d1d5f2bdc1a079609b9d6449165b2466b32a01eac7992a1ea0cac2f223cde1d56f9bbccc67afe44621daf858df3fc0eb837818f3e42ab3e131ce4e492efa63c108e6ef91c29ed63b3045baebcb0ab8d203c7f558beaffccba31b12aca7f54b58d0c28340e4fdb3c7c94fe9c4fef9d640ff2fcff02f1748416cbed0981fbff49f0e39eaf8a30273e67ed851944d33d6a593ef5ddcd62da84568822a6045b633bf6a513b3cfe8f9de13e76f8dcbd915980dec205eab6a5c0c72dcebd9afff1d25509ddbf33f8e24131fbd74cda93336514340cf8036b66b09ed9e6a6ac37e22fb3ac407e321beae8cd9fe74c8aaeb4edaa9a7272848fc623f6fe835a2e647379f547fc5ec6371318a85bfa60009cb20ccbb8a467492988a87633c14c0324ba0d0c3e1798ed29c8494cea35023746da05e35d184b4a301d5b2238d665495c6318b5af8653758008952d06cb9e62487b196d64383c73c06d6e1cccdf9b3ce8f95679e7050d949004a55f4ccf95b2552880ae36d1f7e09504d2338316d87d14a064511a295d768113e301bdf9d4383a8be32192d3f2f3b2de14181c73839a7cb4af5301").unwrap()
 });
+static BLS12381_Q_LENDIAN: Lazy<Vec<u8>> = Lazy::new(|| ark_bls12_381::Fq::MODULUS.to_bytes_le());
+/// generated by: ark_bls12_381::Fq::MODULUS.pow(2)
+static BLS12381_Q2_LENDIAN: Lazy<Vec<u8>> = Lazy::new(|| {
+    hex::decode("398e711c0000aa26ab2e38761d6bed7cfd3c116283332c1643b7713eed91bf6649a09170a8852e297b5c18869c61681d01ef7809334931f56ecadd16fd2ca650d08b9e34499ee566b446700ee590dce2e9252fa2ea78d24bc75fc3b8a437a402").unwrap()
+});
 
 static BN254_GT_GENERATOR: Lazy<ark_bn254::Fq12> = Lazy::new(|| {
     // Gt generator is defined as the `e(g1_generator, g2_generator)`.
@@ -311,6 +530,14 @@ const BN254_R_SCALAR: ark_ff::BigInteger256 = ark_bn254::Fr::MODULUS;
 static BN254_Q_LENDIAN: Lazy<Vec<u8>> = Lazy::new(|| BN254_Q_SCALAR.to_bytes_le());
 const BN254_Q_SCALAR: ark_ff::BigInteger256 = ark_bn254::Fq::MODULUS;
 
+static SECP256K1_R_LENDIAN: Lazy<Vec<u8>> = Lazy::new(|| ark_secp256k1::Fr::MODULUS.to_bytes_le());
+static SECP256K1_Q_LENDIAN: Lazy<Vec<u8>> = Lazy::new(|| ark_secp256k1::Fq::MODULUS.to_bytes_le());
+static SECP256R1_R_LENDIAN: Lazy<Vec<u8>> = Lazy::new(|| ark_secp256r1::Fr::MODULUS.to_bytes_le());
+static SECP256R1_Q_LENDIAN: Lazy<Vec<u8>> = Lazy::new(|| ark_secp256r1::Fq::MODULUS.to_bytes_le());
+// Vesta reuses these, as its scalar field is the Pallas base field and vice versa.
+static PALLAS_R_LENDIAN: Lazy<Vec<u8>> = Lazy::new(|| ark_pallas::Fr::MODULUS.to_bytes_le());
+static PALLAS_Q_LENDIAN: Lazy<Vec<u8>> = Lazy::new(|| ark_pallas::Fq::MODULUS.to_bytes_le());
+
 /// generated by: ark_bn254::Fq::MODULUS.pow(12)
 static BN254_Q12_LENDIAN: Lazy<Vec<u8>> = Lazy::new(|| {
     hex::decode("21f186cad2e2d4c1dbaf8a066b0ebf41f734e3f859b1c523a6c1f4d457413fdbe3cd44add090135d3ae519acc30ee3bdb6bfac6573b767e975b18a77d53cdcddebf3672c74da9d1409d51b2b2db7ff000d59e3aa7cf09220159f925c86b65459ca6558c4eaa703bf45d85030ff85cc6a879c7e2c4034f7045faf20e4d3dcfffac5eb6634c3e7b939b69b2be70bdf6b9a4680297839b4e3a48cd746bd4d0ea82749ffb7e71bd9b3fb10aa684d71e6adab1250b1d8604d91b51c76c256a50b60ddba2f52b6cc853ac926c6ea86d09d400b2f2330e5c8e92e38905ba50a50c9e11cd979c284bf1327ccdc051a6da1a4a7eac5cec16757a27a1a2311bedd108a9b21ac0814269e7523a5dd3a1f5f4767ffe504a6cb3994fb0ec98d5cd5da00b9cb1188a85f2aa871ecb8a0f9d64141f1ccd2699c138e0ef9ac4d8d6a692b29db0f38b60eb08426ab46109fbab9a5221bb44dd338aafebcc4e6c10dd933597f3ff44ba41d04e82871447f3a759cfa9397c22c0c77f13618dfb65adc8aacf008").unwrap()
@@ -326,8 +553,11 @@ pub fn make_all(
             "deserialize_internal",
             deserialize_internal as RawSafeNative,
         ),
+        ("deserialize_checked_internal", deserialize_checked_internal),
         ("downcast_internal", downcast_internal),
         ("eq_internal", eq_internal),
+        ("is_one_internal", is_one_internal),
+        ("is_zero_internal", is_zero_internal),
         ("add_internal", add_internal),
         ("div_internal", div_internal),
         ("inv_internal", inv_internal),
@@ -339,6 +569,8 @@ pub fn make_all(
         ("zero_internal", zero_internal),
         ("from_u64_internal", from_u64_internal),
         ("double_internal", double_internal),
+        ("fq12_cyclotomic_pow_internal", fq12_cyclotomic_pow_internal),
+        ("pow_u64_internal", pow_u64_internal),
         ("multi_scalar_mul_internal", multi_scalar_mul_internal),
         ("order_internal", order_internal),
         ("scalar_mul_internal", scalar_mul_internal),
@@ -351,10 +583,16 @@ pub fn make_all(
 
     // Test-only natives.
     #[cfg(feature = "testing")]
-    natives.extend([(
-        "rand_insecure_internal",
-        rand_insecure_internal as RawSafeNative,
-    )]);
+    natives.extend([
+        (
+            "rand_insecure_internal",
+            rand_insecure_internal as RawSafeNative,
+        ),
+        (
+            "point_from_coordinates_internal",
+            point_from_coordinates_internal,
+        ),
+    ]);
 
     builder.make_named_natives(natives)
 }
@@ -4,15 +4,27 @@
 use crate::{
     abort_unless_arithmetics_enabled_for_structure, abort_unless_feature_flag_enabled,
     natives::cryptography::algebra::{
-        feature_flag_from_structure, AlgebraContext, Structure, E_TOO_MUCH_MEMORY_USED,
+        feature_flag_from_structure, grumpkin, AlgebraContext, Structure, E_TOO_MUCH_MEMORY_USED,
         MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
     },
     store_element, structure_from_ty_arg,
 };
+#[cfg(feature = "testing")]
+use crate::{
+    natives::cryptography::algebra::{
+        MOVE_ABORT_CODE_MALFORMED_POINT_ENCODING, MOVE_ABORT_CODE_POINT_NOT_IN_SUBGROUP,
+        MOVE_ABORT_CODE_POINT_NOT_ON_CURVE,
+    },
+    store_constructed_point,
+};
 use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
 use aptos_native_interface::{
     safely_pop_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
 };
+#[cfg(feature = "testing")]
+use ark_serialize::CanonicalDeserialize;
+#[cfg(feature = "testing")]
+use move_vm_types::values::VectorRef;
 use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
 use smallvec::{smallvec, SmallVec};
 use std::{collections::VecDeque, rc::Rc};
@@ -42,6 +54,18 @@ pub fn from_u64_internal(
             ark_bls12_381::Fr,
             ALGEBRA_ARK_BLS12_381_FR_FROM_U64
         ),
+        Some(Structure::BLS12381Fq) => from_u64_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq,
+            ALGEBRA_ARK_BLS12_381_FQ_FROM_U64
+        ),
+        Some(Structure::BLS12381Fq2) => from_u64_internal!(
+            context,
+            args,
+            ark_bls12_381::Fq2,
+            ALGEBRA_ARK_BLS12_381_FQ2_FROM_U64
+        ),
         Some(Structure::BLS12381Fq12) => from_u64_internal!(
             context,
             args,
@@ -60,6 +84,134 @@ pub fn from_u64_internal(
             ark_bn254::Fq12,
             ALGEBRA_ARK_BN254_FQ12_FROM_U64
         ),
+        Some(Structure::Secp256k1Fr) => {
+            from_u64_internal!(
+                context,
+                args,
+                ark_secp256k1::Fr,
+                ALGEBRA_ARK_SECP256K1_FR_FROM_U64
+            )
+        },
+        Some(Structure::Secp256k1Fq) => {
+            from_u64_internal!(
+                context,
+                args,
+                ark_secp256k1::Fq,
+                ALGEBRA_ARK_SECP256K1_FQ_FROM_U64
+            )
+        },
+        Some(Structure::Secp256r1Fr) => {
+            from_u64_internal!(
+                context,
+                args,
+                ark_secp256r1::Fr,
+                ALGEBRA_ARK_SECP256R1_FR_FROM_U64
+            )
+        },
+        Some(Structure::Secp256r1Fq) => {
+            from_u64_internal!(
+                context,
+                args,
+                ark_secp256r1::Fq,
+                ALGEBRA_ARK_SECP256R1_FQ_FROM_U64
+            )
+        },
+        Some(Structure::GrumpkinFr) => {
+            from_u64_internal!(
+                context,
+                args,
+                grumpkin::Fr,
+                ALGEBRA_ARK_GRUMPKIN_FR_FROM_U64
+            )
+        },
+        Some(Structure::GrumpkinFq) => {
+            from_u64_internal!(
+                context,
+                args,
+                grumpkin::Fq,
+                ALGEBRA_ARK_GRUMPKIN_FQ_FROM_U64
+            )
+        },
+        Some(Structure::PallasFr) => {
+            from_u64_internal!(
+                context,
+                args,
+                ark_pallas::Fr,
+                ALGEBRA_ARK_PALLAS_FR_FROM_U64
+            )
+        },
+        Some(Structure::PallasFq) => {
+            from_u64_internal!(
+                context,
+                args,
+                ark_pallas::Fq,
+                ALGEBRA_ARK_PALLAS_FQ_FROM_U64
+            )
+        },
+        Some(Structure::VestaFr) => {
+            from_u64_internal!(context, args, ark_vesta::Fr, ALGEBRA_ARK_VESTA_FR_FROM_U64)
+        },
+        Some(Structure::VestaFq) => {
+            from_u64_internal!(context, args, ark_vesta::Fq, ALGEBRA_ARK_VESTA_FQ_FROM_U64)
+        },
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
+
+#[cfg(feature = "testing")]
+macro_rules! ark_point_from_coordinates_internal {
+    ($context:expr, $x_bytes:expr, $y_bytes:expr, $base:ty, $affine:ty) => {{
+        let x = <$base>::deserialize_uncompressed($x_bytes);
+        let y = <$base>::deserialize_uncompressed($y_bytes);
+        match (x, y) {
+            (Ok(x), Ok(y)) => {
+                let point = <$affine>::new_unchecked(x, y);
+                let handle = store_constructed_point!($context, point)?;
+                Ok(smallvec![Value::u64(handle as u64)])
+            },
+            _ => Err(SafeNativeError::Abort {
+                abort_code: MOVE_ABORT_CODE_MALFORMED_POINT_ENCODING,
+            }),
+        }
+    }};
+}
+
+/// Construct a group element from its affine coordinates,
+/// each in the uncompressed serialization of the base field.
+/// Unlike deserialization, ark does not validate the coordinates,
+/// so the on-curve and subgroup checks happen on storage.
+#[cfg(feature = "testing")]
+pub fn point_from_coordinates_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(1, ty_args.len());
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    abort_unless_arithmetics_enabled_for_structure!(context, structure_opt);
+    let y_ref = safely_pop_arg!(args, VectorRef);
+    let y_bytes_ref = y_ref.as_bytes_ref();
+    let y_bytes = y_bytes_ref.as_slice();
+    let x_ref = safely_pop_arg!(args, VectorRef);
+    let x_bytes_ref = x_ref.as_bytes_ref();
+    let x_bytes = x_bytes_ref.as_slice();
+    match structure_opt {
+        Some(Structure::BN254G1) => ark_point_from_coordinates_internal!(
+            context,
+            x_bytes,
+            y_bytes,
+            ark_bn254::Fq,
+            ark_bn254::G1Affine
+        ),
+        Some(Structure::BN254G2) => ark_point_from_coordinates_internal!(
+            context,
+            x_bytes,
+            y_bytes,
+            ark_bn254::Fq2,
+            ark_bn254::G2Affine
+        ),
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
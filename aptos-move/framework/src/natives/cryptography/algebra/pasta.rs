@@ -0,0 +1,78 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! The point encoding of the `pasta_curves` crate, used by the Pallas and Vesta structures.
+//! Like `sec1`, it only depends on arkworks, so that the benches can time it.
+
+use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_serialize::CanonicalDeserialize;
+
+/// Encode a short Weierstrass point in the format of the `pasta_curves` crate.
+/// The compressed form is `x` in little-endian with the parity of `y` in the most significant bit,
+/// and the uncompressed form is `x || y`, both in little-endian.
+/// The point at infinity is encoded as all zeros.
+pub fn pasta_serialize<P: SWCurveConfig>(point: &Affine<P>, compressed: bool) -> Vec<u8>
+where
+    P::BaseField: PrimeField,
+{
+    let num_bytes = (P::BaseField::MODULUS_BIT_SIZE as usize + 7) / 8;
+    if point.infinity {
+        return vec![0x00; if compressed { num_bytes } else { 2 * num_bytes }];
+    }
+    let mut buf = point.x.into_bigint().to_bytes_le();
+    if compressed {
+        if point.y.into_bigint().is_odd() {
+            buf[num_bytes - 1] |= 0x80;
+        }
+    } else {
+        buf.extend(point.y.into_bigint().to_bytes_le());
+    }
+    buf
+}
+
+/// Decode a short Weierstrass point from the format of the `pasta_curves` crate.
+/// Return `None` if the size does not match `compressed`, or the point is not on the curve.
+pub fn pasta_deserialize<P: SWCurveConfig>(bytes: &[u8], compressed: bool) -> Option<Affine<P>>
+where
+    P::BaseField: PrimeField,
+{
+    let num_bytes = (P::BaseField::MODULUS_BIT_SIZE as usize + 7) / 8;
+    if compressed {
+        if bytes.len() != num_bytes {
+            return None;
+        }
+        let mut x_bytes = bytes.to_vec();
+        let y_is_odd = x_bytes[num_bytes - 1] & 0x80 != 0;
+        x_bytes[num_bytes - 1] &= 0x7F;
+        let x = P::BaseField::deserialize_uncompressed(x_bytes.as_slice()).ok()?;
+        if x.is_zero() && !y_is_odd {
+            return Some(Affine::identity());
+        }
+        let (y_0, y_1) = Affine::<P>::get_ys_from_x_unchecked(x)?;
+        let y = if y_0.into_bigint().is_odd() == y_is_odd {
+            y_0
+        } else {
+            y_1
+        };
+        if y.into_bigint().is_odd() != y_is_odd {
+            return None;
+        }
+        Some(Affine::new_unchecked(x, y))
+    } else {
+        if bytes.len() != 2 * num_bytes {
+            return None;
+        }
+        if bytes.iter().all(|byte| *byte == 0) {
+            return Some(Affine::identity());
+        }
+        let (x_bytes, y_bytes) = bytes.split_at(num_bytes);
+        let x = P::BaseField::deserialize_uncompressed(x_bytes).ok()?;
+        let y = P::BaseField::deserialize_uncompressed(y_bytes).ok()?;
+        let point = Affine::new_unchecked(x, y);
+        if !point.is_on_curve() {
+            return None;
+        }
+        Some(point)
+    }
+}
@@ -4,7 +4,7 @@
 #[cfg(feature = "testing")]
 use crate::{
     natives::cryptography::algebra::{
-        AlgebraContext, Structure, BLS12381_GT_GENERATOR, BN254_GT_GENERATOR,
+        grumpkin, AlgebraContext, Structure, BLS12381_GT_GENERATOR, BN254_GT_GENERATOR,
         E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES,
     },
     structure_from_ty_arg,
@@ -60,6 +60,12 @@ pub fn rand_insecure_internal(
         Some(Structure::BLS12381Fr) => {
             ark_rand_internal!(context, ark_bls12_381::Fr)
         },
+        Some(Structure::BLS12381Fq) => {
+            ark_rand_internal!(context, ark_bls12_381::Fq)
+        },
+        Some(Structure::BLS12381Fq2) => {
+            ark_rand_internal!(context, ark_bls12_381::Fq2)
+        },
         Some(Structure::BLS12381Fq12) => {
             ark_rand_internal!(context, ark_bls12_381::Fq12)
         },
@@ -102,6 +108,51 @@ pub fn rand_insecure_internal(
                 Err(abort_code) => Err(SafeNativeError::Abort { abort_code }),
             }
         },
+        Some(Structure::Secp256k1Fr) => {
+            ark_rand_internal!(context, ark_secp256k1::Fr)
+        },
+        Some(Structure::Secp256k1Fq) => {
+            ark_rand_internal!(context, ark_secp256k1::Fq)
+        },
+        Some(Structure::Secp256k1G1) => {
+            ark_rand_internal!(context, ark_secp256k1::Projective)
+        },
+        Some(Structure::Secp256r1Fr) => {
+            ark_rand_internal!(context, ark_secp256r1::Fr)
+        },
+        Some(Structure::Secp256r1Fq) => {
+            ark_rand_internal!(context, ark_secp256r1::Fq)
+        },
+        Some(Structure::Secp256r1G1) => {
+            ark_rand_internal!(context, ark_secp256r1::Projective)
+        },
+        Some(Structure::GrumpkinFr) => {
+            ark_rand_internal!(context, grumpkin::Fr)
+        },
+        Some(Structure::GrumpkinFq) => {
+            ark_rand_internal!(context, grumpkin::Fq)
+        },
+        Some(Structure::GrumpkinG1) => {
+            ark_rand_internal!(context, grumpkin::Projective)
+        },
+        Some(Structure::PallasFr) => {
+            ark_rand_internal!(context, ark_pallas::Fr)
+        },
+        Some(Structure::PallasFq) => {
+            ark_rand_internal!(context, ark_pallas::Fq)
+        },
+        Some(Structure::PallasG1) => {
+            ark_rand_internal!(context, ark_pallas::Projective)
+        },
+        Some(Structure::VestaFr) => {
+            ark_rand_internal!(context, ark_vesta::Fr)
+        },
+        Some(Structure::VestaFq) => {
+            ark_rand_internal!(context, ark_vesta::Fq)
+        },
+        Some(Structure::VestaG1) => {
+            ark_rand_internal!(context, ark_vesta::Projective)
+        },
         _ => unreachable!(),
     }
 }
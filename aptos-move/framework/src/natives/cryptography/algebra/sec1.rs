@@ -0,0 +1,88 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! The SEC1 point encoding used by the secp256k1 and secp256r1 structures.
+//! It only depends on arkworks, so that the benches can time the exact code the natives run.
+
+use ark_ec::short_weierstrass::{Affine, SWCurveConfig};
+use ark_ff::{BigInteger, PrimeField};
+
+/// Encode a short Weierstrass point in the SEC1 format (section 2.3.3 of <https://www.secg.org/sec1-v2.pdf>).
+/// The point at infinity is encoded as a single `0x00` byte.
+pub fn sec1_serialize<P: SWCurveConfig>(point: &Affine<P>, compressed: bool) -> Vec<u8>
+where
+    P::BaseField: PrimeField,
+{
+    if point.infinity {
+        return vec![0x00];
+    }
+    let x_bytes = point.x.into_bigint().to_bytes_be();
+    let mut buf = Vec::with_capacity(1 + 2 * x_bytes.len());
+    if compressed {
+        buf.push(
+            if point.y.into_bigint().is_odd() {
+                0x03
+            } else {
+                0x02
+            },
+        );
+        buf.extend(x_bytes);
+    } else {
+        buf.push(0x04);
+        buf.extend(x_bytes);
+        buf.extend(point.y.into_bigint().to_bytes_be());
+    }
+    buf
+}
+
+/// Parse a canonical big-endian base field element.
+fn base_field_from_be_bytes<F: PrimeField>(bytes: &[u8]) -> Option<F> {
+    if bytes.len() != (F::MODULUS_BIT_SIZE as usize + 7) / 8 {
+        return None;
+    }
+    let mut bytes_le = bytes.to_vec();
+    bytes_le.reverse();
+    F::deserialize_uncompressed(bytes_le.as_slice()).ok()
+}
+
+/// Decode a short Weierstrass point from the SEC1 format (section 2.3.4 of <https://www.secg.org/sec1-v2.pdf>).
+/// Return `None` if the encoding does not match `compressed`, or the point is not in the prime-order subgroup.
+pub fn sec1_deserialize<P: SWCurveConfig>(bytes: &[u8], compressed: bool) -> Option<Affine<P>>
+where
+    P::BaseField: PrimeField,
+{
+    let point = match (bytes, compressed) {
+        ([0x00], _) => return Some(Affine::identity()),
+        ([tag @ (0x02 | 0x03), x_bytes @ ..], true) => {
+            let x = base_field_from_be_bytes::<P::BaseField>(x_bytes)?;
+            let (y_0, y_1) = Affine::<P>::get_ys_from_x_unchecked(x)?;
+            let y_is_odd = *tag == 0x03;
+            let y = if y_0.into_bigint().is_odd() == y_is_odd {
+                y_0
+            } else {
+                y_1
+            };
+            if y.into_bigint().is_odd() != y_is_odd {
+                return None;
+            }
+            Affine::new_unchecked(x, y)
+        },
+        ([0x04, xy_bytes @ ..], false) if xy_bytes.len() % 2 == 0 => {
+            let (x_bytes, y_bytes) = xy_bytes.split_at(xy_bytes.len() / 2);
+            let x = base_field_from_be_bytes::<P::BaseField>(x_bytes)?;
+            let y = base_field_from_be_bytes::<P::BaseField>(y_bytes)?;
+            let point = Affine::new_unchecked(x, y);
+            if !point.is_on_curve() {
+                return None;
+            }
+            point
+        },
+        _ => return None,
+    };
+    // With a cofactor of 1, every point on the curve is in the prime-order subgroup. Skip the check then,
+    // as arkworks does it by a scalar multiplication with the group order.
+    if P::COFACTOR != [1] && !point.is_in_correct_subgroup_assuming_on_curve() {
+        return None;
+    }
+    Some(point)
+}
@@ -4,9 +4,13 @@
 use crate::{
     abort_unless_feature_flag_enabled,
     natives::cryptography::algebra::{
-        abort_invariant_violated, AlgebraContext, SerializationFormat, Structure,
-        BLS12381_R_SCALAR, BN254_R_SCALAR, E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES,
-        MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        abort_invariant_violated, grumpkin,
+        pasta::{pasta_deserialize, pasta_serialize},
+        sec1::{sec1_deserialize, sec1_serialize},
+        AlgebraContext, SerializationFormat, Structure, BLS12381_R_SCALAR, BN254_R_SCALAR,
+        E_TOO_MUCH_MEMORY_USED, MEMORY_LIMIT_IN_BYTES, MOVE_ABORT_CODE_MALFORMED_POINT_ENCODING,
+        MOVE_ABORT_CODE_NOT_IMPLEMENTED, MOVE_ABORT_CODE_POINT_NOT_IN_SUBGROUP,
+        MOVE_ABORT_CODE_POINT_NOT_ON_CURVE,
     },
     safe_borrow_element, store_element, structure_from_ty_arg,
 };
@@ -15,9 +19,12 @@ use aptos_native_interface::{
     safely_pop_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
 };
 use aptos_types::on_chain_config::FeatureFlag;
-use ark_ec::CurveGroup;
+use ark_ec::{
+    short_weierstrass::{Affine, SWCurveConfig, SWFlags},
+    CurveGroup,
+};
 use ark_ff::Field;
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_serialize::{CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize};
 use move_vm_types::{
     loaded_data::runtime_types::Type,
     values::{Value, VectorRef},
@@ -38,6 +45,11 @@ pub fn feature_flag_of_serialization_format(
         | Some(SerializationFormat::BLS12381G2Uncompressed)
         | Some(SerializationFormat::BLS12381G2Compressed)
         | Some(SerializationFormat::BLS12381Gt) => Some(FeatureFlag::BLS12_381_STRUCTURES),
+        Some(SerializationFormat::BLS12381FqLsb)
+        | Some(SerializationFormat::BLS12381FqMsb)
+        | Some(SerializationFormat::BLS12381Fq2LscLsb) => {
+            Some(FeatureFlag::BLS12_381_BASE_FIELD_STRUCTURES)
+        },
         Some(SerializationFormat::BN254FrLsb)
         | Some(SerializationFormat::BN254FrMsb)
         | Some(SerializationFormat::BN254FqLsb)
@@ -48,6 +60,40 @@ pub fn feature_flag_of_serialization_format(
         | Some(SerializationFormat::BN254G2Uncompressed)
         | Some(SerializationFormat::BN254G2Compressed)
         | Some(SerializationFormat::BN254Gt) => Some(FeatureFlag::BN254_STRUCTURES),
+        Some(SerializationFormat::Secp256k1FrLsb)
+        | Some(SerializationFormat::Secp256k1FrMsb)
+        | Some(SerializationFormat::Secp256k1FqLsb)
+        | Some(SerializationFormat::Secp256k1FqMsb)
+        | Some(SerializationFormat::Secp256k1G1Compressed)
+        | Some(SerializationFormat::Secp256k1G1Uncompressed)
+        | Some(SerializationFormat::Secp256r1FrLsb)
+        | Some(SerializationFormat::Secp256r1FrMsb)
+        | Some(SerializationFormat::Secp256r1FqLsb)
+        | Some(SerializationFormat::Secp256r1FqMsb)
+        | Some(SerializationFormat::Secp256r1G1Compressed)
+        | Some(SerializationFormat::Secp256r1G1Uncompressed) => {
+            Some(FeatureFlag::SECP256K1_SECP256R1_STRUCTURES)
+        },
+        Some(SerializationFormat::GrumpkinFrLsb)
+        | Some(SerializationFormat::GrumpkinFrMsb)
+        | Some(SerializationFormat::GrumpkinFqLsb)
+        | Some(SerializationFormat::GrumpkinFqMsb)
+        | Some(SerializationFormat::GrumpkinG1Compressed)
+        | Some(SerializationFormat::GrumpkinG1Uncompressed) => {
+            Some(FeatureFlag::GRUMPKIN_STRUCTURES)
+        },
+        Some(SerializationFormat::PallasFrLsb)
+        | Some(SerializationFormat::PallasFrMsb)
+        | Some(SerializationFormat::PallasFqLsb)
+        | Some(SerializationFormat::PallasFqMsb)
+        | Some(SerializationFormat::PallasG1Compressed)
+        | Some(SerializationFormat::PallasG1Uncompressed)
+        | Some(SerializationFormat::VestaFrLsb)
+        | Some(SerializationFormat::VestaFrMsb)
+        | Some(SerializationFormat::VestaFqLsb)
+        | Some(SerializationFormat::VestaFqMsb)
+        | Some(SerializationFormat::VestaG1Compressed)
+        | Some(SerializationFormat::VestaG1Uncompressed) => Some(FeatureFlag::PASTA_STRUCTURES),
         _ => None,
     }
 }
@@ -66,6 +112,25 @@ macro_rules! format_from_ty_arg {
     }};
 }
 
+/// Decompress a short Weierstrass point from the arkworks compressed format,
+/// checking the curve equation and the subgroup membership as separate steps.
+/// Return the Move abort code of the first check that fails.
+fn ark_decompress_checked<P: SWCurveConfig>(bytes: &[u8]) -> Result<Affine<P>, u64> {
+    let (x, flags) = P::BaseField::deserialize_with_flags::<_, SWFlags>(bytes)
+        .map_err(|_| MOVE_ABORT_CODE_MALFORMED_POINT_ENCODING)?;
+    let greatest = match flags.is_positive() {
+        Some(greatest) => greatest,
+        None => return Ok(Affine::identity()),
+    };
+    // Only the x-coordinate is encoded, so the point is on the curve iff `x^3 + ax + b` is a square.
+    let point = Affine::<P>::get_point_from_x_unchecked(x, greatest)
+        .ok_or(MOVE_ABORT_CODE_POINT_NOT_ON_CURVE)?;
+    if !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(MOVE_ABORT_CODE_POINT_NOT_IN_SUBGROUP);
+    }
+    Ok(point)
+}
+
 macro_rules! serialize_element {
     (
         $context:expr,
@@ -73,7 +138,8 @@ macro_rules! serialize_element {
         $structure_to_match:expr,
         $format_to_match:expr,
         [$(($field_structure:pat, $field_format:pat, $field_ty:ty, $field_serialization_func:ident,$reverse:expr, $field_serialization_gas:expr)),* $(,)?],
-        [$(($curve_structure:pat,$curve_format:pat, $curve_ty:ty, $curve_serialization_func:ident, $curve_serialization_gas:expr)),* $(,)?]
+        [$(($curve_structure:pat,$curve_format:pat, $curve_ty:ty, $curve_serialization_func:ident, $curve_serialization_gas:expr)),* $(,)?],
+        [$(($point_structure:pat, $point_format:pat, $point_ty:ty, $point_encode_func:ident, $point_compressed:expr, $point_serialization_gas:expr)),* $(,)?]
     ) => {
         match ($structure_to_match, $format_to_match) {
         $(
@@ -109,6 +175,16 @@ macro_rules! serialize_element {
                 .map_err(|_e| abort_invariant_violated())?;
             Ok(smallvec![Value::vector_u8(buf)])
           }
+        )*
+        $(
+          ($point_structure,$point_format) => {
+            let handle = safely_pop_arg!($args, u64) as usize;
+            safe_borrow_element!($context, handle, $point_ty, element_ptr, element);
+            let element_affine = element.into_affine();
+            $context.charge($point_serialization_gas)?;
+            let buf = $point_encode_func(&element_affine, $point_compressed);
+            Ok(smallvec![Value::vector_u8(buf)])
+          }
         )*
           _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
@@ -149,6 +225,30 @@ pub fn serialize_internal(
                     true,
                     ALGEBRA_ARK_BLS12_381_FR_SERIALIZE
                 ),
+                (
+                    Structure::BLS12381Fq,
+                    SerializationFormat::BLS12381FqLsb,
+                    ark_bls12_381::Fq,
+                    serialize_uncompressed,
+                    false,
+                    ALGEBRA_ARK_BLS12_381_FQ_SERIALIZE
+                ),
+                (
+                    Structure::BLS12381Fq,
+                    SerializationFormat::BLS12381FqMsb,
+                    ark_bls12_381::Fq,
+                    serialize_uncompressed,
+                    true,
+                    ALGEBRA_ARK_BLS12_381_FQ_SERIALIZE
+                ),
+                (
+                    Structure::BLS12381Fq2,
+                    SerializationFormat::BLS12381Fq2LscLsb,
+                    ark_bls12_381::Fq2,
+                    serialize_uncompressed,
+                    false,
+                    ALGEBRA_ARK_BLS12_381_FQ2_SERIALIZE
+                ),
                 (
                     Structure::BLS12381Fq12,
                     SerializationFormat::BLS12381Fq12LscLsb,
@@ -212,6 +312,166 @@ pub fn serialize_internal(
                     serialize_uncompressed,
                     false,
                     ALGEBRA_ARK_BN254_FQ12_SERIALIZE
+                ),
+                (
+                    Structure::Secp256k1Fr,
+                    SerializationFormat::Secp256k1FrLsb,
+                    ark_secp256k1::Fr,
+                    serialize_uncompressed,
+                    false,
+                    ALGEBRA_ARK_SECP256K1_FR_SERIALIZE
+                ),
+                (
+                    Structure::Secp256k1Fr,
+                    SerializationFormat::Secp256k1FrMsb,
+                    ark_secp256k1::Fr,
+                    serialize_uncompressed,
+                    true,
+                    ALGEBRA_ARK_SECP256K1_FR_SERIALIZE
+                ),
+                (
+                    Structure::Secp256k1Fq,
+                    SerializationFormat::Secp256k1FqLsb,
+                    ark_secp256k1::Fq,
+                    serialize_uncompressed,
+                    false,
+                    ALGEBRA_ARK_SECP256K1_FQ_SERIALIZE
+                ),
+                (
+                    Structure::Secp256k1Fq,
+                    SerializationFormat::Secp256k1FqMsb,
+                    ark_secp256k1::Fq,
+                    serialize_uncompressed,
+                    true,
+                    ALGEBRA_ARK_SECP256K1_FQ_SERIALIZE
+                ),
+                (
+                    Structure::Secp256r1Fr,
+                    SerializationFormat::Secp256r1FrLsb,
+                    ark_secp256r1::Fr,
+                    serialize_uncompressed,
+                    false,
+                    ALGEBRA_ARK_SECP256R1_FR_SERIALIZE
+                ),
+                (
+                    Structure::Secp256r1Fr,
+                    SerializationFormat::Secp256r1FrMsb,
+                    ark_secp256r1::Fr,
+                    serialize_uncompressed,
+                    true,
+                    ALGEBRA_ARK_SECP256R1_FR_SERIALIZE
+                ),
+                (
+                    Structure::Secp256r1Fq,
+                    SerializationFormat::Secp256r1FqLsb,
+                    ark_secp256r1::Fq,
+                    serialize_uncompressed,
+                    false,
+                    ALGEBRA_ARK_SECP256R1_FQ_SERIALIZE
+                ),
+                (
+                    Structure::Secp256r1Fq,
+                    SerializationFormat::Secp256r1FqMsb,
+                    ark_secp256r1::Fq,
+                    serialize_uncompressed,
+                    true,
+                    ALGEBRA_ARK_SECP256R1_FQ_SERIALIZE
+                ),
+                (
+                    Structure::GrumpkinFr,
+                    SerializationFormat::GrumpkinFrLsb,
+                    grumpkin::Fr,
+                    serialize_uncompressed,
+                    false,
+                    ALGEBRA_ARK_GRUMPKIN_FR_SERIALIZE
+                ),
+                (
+                    Structure::GrumpkinFr,
+                    SerializationFormat::GrumpkinFrMsb,
+                    grumpkin::Fr,
+                    serialize_uncompressed,
+                    true,
+                    ALGEBRA_ARK_GRUMPKIN_FR_SERIALIZE
+                ),
+                (
+                    Structure::GrumpkinFq,
+                    SerializationFormat::GrumpkinFqLsb,
+                    grumpkin::Fq,
+                    serialize_uncompressed,
+                    false,
+                    ALGEBRA_ARK_GRUMPKIN_FQ_SERIALIZE
+                ),
+                (
+                    Structure::GrumpkinFq,
+                    SerializationFormat::GrumpkinFqMsb,
+                    grumpkin::Fq,
+                    serialize_uncompressed,
+                    true,
+                    ALGEBRA_ARK_GRUMPKIN_FQ_SERIALIZE
+                ),
+                (
+                    Structure::PallasFr,
+                    SerializationFormat::PallasFrLsb,
+                    ark_pallas::Fr,
+                    serialize_uncompressed,
+                    false,
+                    ALGEBRA_ARK_PALLAS_FR_SERIALIZE
+                ),
+                (
+                    Structure::PallasFr,
+                    SerializationFormat::PallasFrMsb,
+                    ark_pallas::Fr,
+                    serialize_uncompressed,
+                    true,
+                    ALGEBRA_ARK_PALLAS_FR_SERIALIZE
+                ),
+                (
+                    Structure::PallasFq,
+                    SerializationFormat::PallasFqLsb,
+                    ark_pallas::Fq,
+                    serialize_uncompressed,
+                    false,
+                    ALGEBRA_ARK_PALLAS_FQ_SERIALIZE
+                ),
+                (
+                    Structure::PallasFq,
+                    SerializationFormat::PallasFqMsb,
+                    ark_pallas::Fq,
+                    serialize_uncompressed,
+                    true,
+                    ALGEBRA_ARK_PALLAS_FQ_SERIALIZE
+                ),
+                (
+                    Structure::VestaFr,
+                    SerializationFormat::VestaFrLsb,
+                    ark_vesta::Fr,
+                    serialize_uncompressed,
+                    false,
+                    ALGEBRA_ARK_VESTA_FR_SERIALIZE
+                ),
+                (
+                    Structure::VestaFr,
+                    SerializationFormat::VestaFrMsb,
+                    ark_vesta::Fr,
+                    serialize_uncompressed,
+                    true,
+                    ALGEBRA_ARK_VESTA_FR_SERIALIZE
+                ),
+                (
+                    Structure::VestaFq,
+                    SerializationFormat::VestaFqLsb,
+                    ark_vesta::Fq,
+                    serialize_uncompressed,
+                    false,
+                    ALGEBRA_ARK_VESTA_FQ_SERIALIZE
+                ),
+                (
+                    Structure::VestaFq,
+                    SerializationFormat::VestaFqMsb,
+                    ark_vesta::Fq,
+                    serialize_uncompressed,
+                    true,
+                    ALGEBRA_ARK_VESTA_FQ_SERIALIZE
                 )
             ],
             [
@@ -271,6 +531,86 @@ pub fn serialize_internal(
                     serialize_compressed,
                     ALGEBRA_ARK_BN254_G2_AFFINE_SERIALIZE_COMP
                 ),
+                (
+                    Structure::GrumpkinG1,
+                    SerializationFormat::GrumpkinG1Uncompressed,
+                    grumpkin::Projective,
+                    serialize_uncompressed,
+                    ALGEBRA_ARK_GRUMPKIN_G1_AFFINE_SERIALIZE_UNCOMP
+                ),
+                (
+                    Structure::GrumpkinG1,
+                    SerializationFormat::GrumpkinG1Compressed,
+                    grumpkin::Projective,
+                    serialize_compressed,
+                    ALGEBRA_ARK_GRUMPKIN_G1_AFFINE_SERIALIZE_COMP
+                ),
+            ],
+            [
+                (
+                    Structure::Secp256k1G1,
+                    SerializationFormat::Secp256k1G1Uncompressed,
+                    ark_secp256k1::Projective,
+                    sec1_serialize,
+                    false,
+                    ALGEBRA_ARK_SECP256K1_G1_AFFINE_SERIALIZE_UNCOMP
+                ),
+                (
+                    Structure::Secp256k1G1,
+                    SerializationFormat::Secp256k1G1Compressed,
+                    ark_secp256k1::Projective,
+                    sec1_serialize,
+                    true,
+                    ALGEBRA_ARK_SECP256K1_G1_AFFINE_SERIALIZE_COMP
+                ),
+                (
+                    Structure::Secp256r1G1,
+                    SerializationFormat::Secp256r1G1Uncompressed,
+                    ark_secp256r1::Projective,
+                    sec1_serialize,
+                    false,
+                    ALGEBRA_ARK_SECP256R1_G1_AFFINE_SERIALIZE_UNCOMP
+                ),
+                (
+                    Structure::Secp256r1G1,
+                    SerializationFormat::Secp256r1G1Compressed,
+                    ark_secp256r1::Projective,
+                    sec1_serialize,
+                    true,
+                    ALGEBRA_ARK_SECP256R1_G1_AFFINE_SERIALIZE_COMP
+                ),
+                (
+                    Structure::PallasG1,
+                    SerializationFormat::PallasG1Uncompressed,
+                    ark_pallas::Projective,
+                    pasta_serialize,
+                    false,
+                    ALGEBRA_ARK_PALLAS_G1_AFFINE_SERIALIZE_UNCOMP
+                ),
+                (
+                    Structure::PallasG1,
+                    SerializationFormat::PallasG1Compressed,
+                    ark_pallas::Projective,
+                    pasta_serialize,
+                    true,
+                    ALGEBRA_ARK_PALLAS_G1_AFFINE_SERIALIZE_COMP
+                ),
+                (
+                    Structure::VestaG1,
+                    SerializationFormat::VestaG1Uncompressed,
+                    ark_vesta::Projective,
+                    pasta_serialize,
+                    false,
+                    ALGEBRA_ARK_VESTA_G1_AFFINE_SERIALIZE_UNCOMP
+                ),
+                (
+                    Structure::VestaG1,
+                    SerializationFormat::VestaG1Compressed,
+                    ark_vesta::Projective,
+                    pasta_serialize,
+                    true,
+                    ALGEBRA_ARK_VESTA_G1_AFFINE_SERIALIZE_COMP
+                ),
             ]
         )
     } else {
@@ -320,6 +660,35 @@ macro_rules! ark_ec_point_deserialize_internal {
     }};
 }
 
+macro_rules! encoded_point_deserialize_internal {
+    ($context:expr, $bytes:expr, $decode_func:ident, $config:ty, $compressed:expr, $gas:expr) => {{
+        $context.charge($gas)?;
+        match $decode_func::<$config>($bytes, $compressed) {
+            Some(element) => {
+                let element_proj = ark_ec::short_weierstrass::Projective::from(element);
+                let handle = store_element!($context, element_proj)?;
+                Ok(smallvec![Value::bool(true), Value::u64(handle as u64)])
+            },
+            None => Ok(smallvec![Value::bool(false), Value::u64(0)]),
+        }
+    }};
+}
+
+/// Macros that implements `deserialize_checked_internal()` using arkworks libraries.
+macro_rules! ark_ec_point_deserialize_checked_internal {
+    ($context:expr, $bytes:expr, $config:ty, $gas:expr) => {{
+        $context.charge($gas)?;
+        match ark_decompress_checked::<$config>($bytes) {
+            Ok(element) => {
+                let element_proj = ark_ec::short_weierstrass::Projective::from(element);
+                let handle = store_element!($context, element_proj)?;
+                Ok(smallvec![Value::u64(handle as u64)])
+            },
+            Err(abort_code) => Err(SafeNativeError::Abort { abort_code }),
+        }
+    }};
+}
+
 pub fn deserialize_internal(
     context: &mut SafeNativeContext,
     ty_args: Vec<Type>,
@@ -364,6 +733,48 @@ pub fn deserialize_internal(
                 ALGEBRA_ARK_BLS12_381_FR_DESER
             )
         },
+        (Some(Structure::BLS12381Fq), Some(SerializationFormat::BLS12381FqLsb)) => {
+            // Valid BLS12381FqLsb serialization should be 48-byte.
+            if bytes.len() != 48 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_bls12_381::Fq,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_BLS12_381_FQ_DESER
+            )
+        },
+        (Some(Structure::BLS12381Fq), Some(SerializationFormat::BLS12381FqMsb)) => {
+            // Valid BLS12381FqMsb serialization should be 48-byte.
+            if bytes.len() != 48 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            let mut bytes_copy: Vec<u8> = bytes.to_vec();
+            bytes_copy.reverse();
+            let bytes = bytes_copy.as_slice();
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_bls12_381::Fq,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_BLS12_381_FQ_DESER
+            )
+        },
+        (Some(Structure::BLS12381Fq2), Some(SerializationFormat::BLS12381Fq2LscLsb)) => {
+            // Valid BLS12381Fq2LscLsb serialization should be 48*2 = 96-byte.
+            if bytes.len() != 96 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_bls12_381::Fq2,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_BLS12_381_FQ2_DESER
+            )
+        },
         (Some(Structure::BLS12381Fq12), Some(SerializationFormat::BLS12381Fq12LscLsb)) => {
             // Valid BLS12381Fq12LscLsb serialization should be 576-byte.
             if bytes.len() != 576 {
@@ -588,6 +999,465 @@ pub fn deserialize_internal(
                 _ => Ok(smallvec![Value::bool(false), Value::u64(0)]),
             }
         },
+        (Some(Structure::Secp256k1Fr), Some(SerializationFormat::Secp256k1FrLsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_secp256k1::Fr,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_SECP256K1_FR_DESER
+            )
+        },
+        (Some(Structure::Secp256k1Fr), Some(SerializationFormat::Secp256k1FrMsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            let mut bytes_copy: Vec<u8> = bytes.to_vec();
+            bytes_copy.reverse();
+            let bytes = bytes_copy.as_slice();
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_secp256k1::Fr,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_SECP256K1_FR_DESER
+            )
+        },
+        (Some(Structure::Secp256k1Fq), Some(SerializationFormat::Secp256k1FqLsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_secp256k1::Fq,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_SECP256K1_FQ_DESER
+            )
+        },
+        (Some(Structure::Secp256k1Fq), Some(SerializationFormat::Secp256k1FqMsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            let mut bytes_copy: Vec<u8> = bytes.to_vec();
+            bytes_copy.reverse();
+            let bytes = bytes_copy.as_slice();
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_secp256k1::Fq,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_SECP256K1_FQ_DESER
+            )
+        },
+        (Some(Structure::Secp256k1G1), Some(SerializationFormat::Secp256k1G1Uncompressed)) => {
+            // Valid SEC1 uncompressed serialization should be 65-byte, or 1-byte for the point at infinity.
+            if bytes.len() != 65 && bytes.len() != 1 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            encoded_point_deserialize_internal!(
+                context,
+                bytes,
+                sec1_deserialize,
+                ark_secp256k1::Config,
+                false,
+                ALGEBRA_ARK_SECP256K1_G1_AFFINE_DESER_UNCOMP
+            )
+        },
+        (Some(Structure::Secp256k1G1), Some(SerializationFormat::Secp256k1G1Compressed)) => {
+            // Valid SEC1 compressed serialization should be 33-byte, or 1-byte for the point at infinity.
+            if bytes.len() != 33 && bytes.len() != 1 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            encoded_point_deserialize_internal!(
+                context,
+                bytes,
+                sec1_deserialize,
+                ark_secp256k1::Config,
+                true,
+                ALGEBRA_ARK_SECP256K1_G1_AFFINE_DESER_COMP
+            )
+        },
+        (Some(Structure::Secp256r1Fr), Some(SerializationFormat::Secp256r1FrLsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_secp256r1::Fr,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_SECP256R1_FR_DESER
+            )
+        },
+        (Some(Structure::Secp256r1Fr), Some(SerializationFormat::Secp256r1FrMsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            let mut bytes_copy: Vec<u8> = bytes.to_vec();
+            bytes_copy.reverse();
+            let bytes = bytes_copy.as_slice();
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_secp256r1::Fr,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_SECP256R1_FR_DESER
+            )
+        },
+        (Some(Structure::Secp256r1Fq), Some(SerializationFormat::Secp256r1FqLsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_secp256r1::Fq,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_SECP256R1_FQ_DESER
+            )
+        },
+        (Some(Structure::Secp256r1Fq), Some(SerializationFormat::Secp256r1FqMsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            let mut bytes_copy: Vec<u8> = bytes.to_vec();
+            bytes_copy.reverse();
+            let bytes = bytes_copy.as_slice();
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_secp256r1::Fq,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_SECP256R1_FQ_DESER
+            )
+        },
+        (Some(Structure::Secp256r1G1), Some(SerializationFormat::Secp256r1G1Uncompressed)) => {
+            // Valid SEC1 uncompressed serialization should be 65-byte, or 1-byte for the point at infinity.
+            if bytes.len() != 65 && bytes.len() != 1 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            encoded_point_deserialize_internal!(
+                context,
+                bytes,
+                sec1_deserialize,
+                ark_secp256r1::Config,
+                false,
+                ALGEBRA_ARK_SECP256R1_G1_AFFINE_DESER_UNCOMP
+            )
+        },
+        (Some(Structure::Secp256r1G1), Some(SerializationFormat::Secp256r1G1Compressed)) => {
+            // Valid SEC1 compressed serialization should be 33-byte, or 1-byte for the point at infinity.
+            if bytes.len() != 33 && bytes.len() != 1 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            encoded_point_deserialize_internal!(
+                context,
+                bytes,
+                sec1_deserialize,
+                ark_secp256r1::Config,
+                true,
+                ALGEBRA_ARK_SECP256R1_G1_AFFINE_DESER_COMP
+            )
+        },
+        (Some(Structure::GrumpkinFr), Some(SerializationFormat::GrumpkinFrLsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                grumpkin::Fr,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_GRUMPKIN_FR_DESER
+            )
+        },
+        (Some(Structure::GrumpkinFr), Some(SerializationFormat::GrumpkinFrMsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            let mut bytes_copy: Vec<u8> = bytes.to_vec();
+            bytes_copy.reverse();
+            let bytes = bytes_copy.as_slice();
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                grumpkin::Fr,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_GRUMPKIN_FR_DESER
+            )
+        },
+        (Some(Structure::GrumpkinFq), Some(SerializationFormat::GrumpkinFqLsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                grumpkin::Fq,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_GRUMPKIN_FQ_DESER
+            )
+        },
+        (Some(Structure::GrumpkinFq), Some(SerializationFormat::GrumpkinFqMsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            let mut bytes_copy: Vec<u8> = bytes.to_vec();
+            bytes_copy.reverse();
+            let bytes = bytes_copy.as_slice();
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                grumpkin::Fq,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_GRUMPKIN_FQ_DESER
+            )
+        },
+        (Some(Structure::GrumpkinG1), Some(SerializationFormat::GrumpkinG1Uncompressed)) => {
+            // Valid GrumpkinG1AffineUncompressed serialization should be 64-byte.
+            if bytes.len() != 64 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            ark_ec_point_deserialize_internal!(
+                context,
+                bytes,
+                grumpkin::Affine,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_GRUMPKIN_G1_AFFINE_DESER_UNCOMP
+            )
+        },
+        (Some(Structure::GrumpkinG1), Some(SerializationFormat::GrumpkinG1Compressed)) => {
+            // Valid GrumpkinG1AffineCompressed serialization should be 32-byte.
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            ark_ec_point_deserialize_internal!(
+                context,
+                bytes,
+                grumpkin::Affine,
+                deserialize_compressed,
+                ALGEBRA_ARK_GRUMPKIN_G1_AFFINE_DESER_COMP
+            )
+        },
+        (Some(Structure::PallasFr), Some(SerializationFormat::PallasFrLsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_pallas::Fr,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_PALLAS_FR_DESER
+            )
+        },
+        (Some(Structure::PallasFr), Some(SerializationFormat::PallasFrMsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            let mut bytes_copy: Vec<u8> = bytes.to_vec();
+            bytes_copy.reverse();
+            let bytes = bytes_copy.as_slice();
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_pallas::Fr,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_PALLAS_FR_DESER
+            )
+        },
+        (Some(Structure::PallasFq), Some(SerializationFormat::PallasFqLsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_pallas::Fq,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_PALLAS_FQ_DESER
+            )
+        },
+        (Some(Structure::PallasFq), Some(SerializationFormat::PallasFqMsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            let mut bytes_copy: Vec<u8> = bytes.to_vec();
+            bytes_copy.reverse();
+            let bytes = bytes_copy.as_slice();
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_pallas::Fq,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_PALLAS_FQ_DESER
+            )
+        },
+        (Some(Structure::PallasG1), Some(SerializationFormat::PallasG1Uncompressed)) => {
+            // Valid PallasG1 uncompressed serialization should be 64-byte.
+            if bytes.len() != 64 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            encoded_point_deserialize_internal!(
+                context,
+                bytes,
+                pasta_deserialize,
+                ark_pallas::PallasConfig,
+                false,
+                ALGEBRA_ARK_PALLAS_G1_AFFINE_DESER_UNCOMP
+            )
+        },
+        (Some(Structure::PallasG1), Some(SerializationFormat::PallasG1Compressed)) => {
+            // Valid PallasG1 compressed serialization should be 32-byte.
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            encoded_point_deserialize_internal!(
+                context,
+                bytes,
+                pasta_deserialize,
+                ark_pallas::PallasConfig,
+                true,
+                ALGEBRA_ARK_PALLAS_G1_AFFINE_DESER_COMP
+            )
+        },
+        (Some(Structure::VestaFr), Some(SerializationFormat::VestaFrLsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_vesta::Fr,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_VESTA_FR_DESER
+            )
+        },
+        (Some(Structure::VestaFr), Some(SerializationFormat::VestaFrMsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            let mut bytes_copy: Vec<u8> = bytes.to_vec();
+            bytes_copy.reverse();
+            let bytes = bytes_copy.as_slice();
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_vesta::Fr,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_VESTA_FR_DESER
+            )
+        },
+        (Some(Structure::VestaFq), Some(SerializationFormat::VestaFqLsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_vesta::Fq,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_VESTA_FQ_DESER
+            )
+        },
+        (Some(Structure::VestaFq), Some(SerializationFormat::VestaFqMsb)) => {
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            let mut bytes_copy: Vec<u8> = bytes.to_vec();
+            bytes_copy.reverse();
+            let bytes = bytes_copy.as_slice();
+            ark_deserialize_internal!(
+                context,
+                bytes,
+                ark_vesta::Fq,
+                deserialize_uncompressed,
+                ALGEBRA_ARK_VESTA_FQ_DESER
+            )
+        },
+        (Some(Structure::VestaG1), Some(SerializationFormat::VestaG1Uncompressed)) => {
+            // Valid VestaG1 uncompressed serialization should be 64-byte.
+            if bytes.len() != 64 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            encoded_point_deserialize_internal!(
+                context,
+                bytes,
+                pasta_deserialize,
+                ark_vesta::VestaConfig,
+                false,
+                ALGEBRA_ARK_VESTA_G1_AFFINE_DESER_UNCOMP
+            )
+        },
+        (Some(Structure::VestaG1), Some(SerializationFormat::VestaG1Compressed)) => {
+            // Valid VestaG1 compressed serialization should be 32-byte.
+            if bytes.len() != 32 {
+                return Ok(smallvec![Value::bool(false), Value::u64(0)]);
+            }
+            encoded_point_deserialize_internal!(
+                context,
+                bytes,
+                pasta_deserialize,
+                ark_vesta::VestaConfig,
+                true,
+                ALGEBRA_ARK_VESTA_G1_AFFINE_DESER_COMP
+            )
+        },
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
+
+pub fn deserialize_checked_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(2, ty_args.len());
+    abort_unless_feature_flag_enabled!(
+        context,
+        Some(FeatureFlag::ALGEBRA_DESERIALIZE_CHECKED_NATIVE)
+    );
+    let structure_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    let format_opt = format_from_ty_arg!(context, &ty_args[1]);
+    abort_unless_serialization_format_enabled!(context, format_opt);
+    let vector_ref = safely_pop_arg!(args, VectorRef);
+    let bytes_ref = vector_ref.as_bytes_ref();
+    let bytes = bytes_ref.as_slice();
+    match (structure_opt, format_opt) {
+        (Some(Structure::BN254G1), Some(SerializationFormat::BN254G1Compressed)) => {
+            // Valid BN254G1AffineCompressed serialization should be 32-byte.
+            if bytes.len() != 32 {
+                return Err(SafeNativeError::Abort {
+                    abort_code: MOVE_ABORT_CODE_MALFORMED_POINT_ENCODING,
+                });
+            }
+            ark_ec_point_deserialize_checked_internal!(
+                context,
+                bytes,
+                ark_bn254::g1::Config,
+                ALGEBRA_ARK_BN254_G1_AFFINE_DESER_COMP
+            )
+        },
+        (Some(Structure::BN254G2), Some(SerializationFormat::BN254G2Compressed)) => {
+            // Valid BN254G2AffineCompressed serialization should be 64-byte.
+            if bytes.len() != 64 {
+                return Err(SafeNativeError::Abort {
+                    abort_code: MOVE_ABORT_CODE_MALFORMED_POINT_ENCODING,
+                });
+            }
+            ark_ec_point_deserialize_checked_internal!(
+                context,
+                bytes,
+                ark_bn254::g2::Config,
+                ALGEBRA_ARK_BN254_G2_AFFINE_DESER_COMP
+            )
+        },
         _ => Err(SafeNativeError::Abort {
             abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
         }),
@@ -0,0 +1,135 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_pop_arg, RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeError,
+    SafeNativeResult,
+};
+use ark_bn254::Bn254;
+use ark_groth16::{Groth16, PreparedVerifyingKey, Proof, VerifyingKey};
+use ark_serialize::CanonicalDeserialize;
+use move_core_types::{gas_algebra::NumArgs, u256::U256};
+use move_vm_runtime::native_functions::NativeFunction;
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    values::{Value, Vector},
+};
+use smallvec::{smallvec, SmallVec};
+use std::collections::VecDeque;
+
+pub mod abort_codes {
+    /// Abort code when the verifying key fails to deserialize (leading 0x01 == INVALID_ARGUMENT)
+    /// NOTE: This must match the code in the Move implementation
+    pub const E_MALFORMED_VERIFYING_KEY: u64 = 0x01_0001;
+
+    /// Abort code when the proof fails to deserialize.
+    /// NOTE: This must match the code in the Move implementation
+    pub const E_MALFORMED_PROOF: u64 = 0x01_0002;
+
+    /// Abort code when the number of public inputs does not match the verifying key.
+    /// NOTE: This must match the code in the Move implementation
+    pub const E_WRONG_NUMBER_OF_PUBLIC_INPUTS: u64 = 0x01_0003;
+
+    /// Abort code when a public input is not smaller than the BN254 scalar field modulus.
+    /// NOTE: This must match the code in the Move implementation
+    pub const E_PUBLIC_INPUT_NOT_CANONICAL: u64 = 0x01_0004;
+}
+
+/// Compressed sizes of the BN254 `G1` and `G2` points in the arkworks format.
+const G1_COMPRESSED_SIZE: usize = 32;
+const G2_COMPRESSED_SIZE: usize = 64;
+
+/// A verifying key is `alpha_g1 || beta_g2 || gamma_g2 || delta_g2 || len || gamma_abc_g1`,
+/// where `len` is the number of `gamma_abc_g1` points as a little-endian `u64`.
+const VK_GAMMA_ABC_LEN_OFFSET: usize = G1_COMPRESSED_SIZE + 3 * G2_COMPRESSED_SIZE;
+const VK_GAMMA_ABC_OFFSET: usize = VK_GAMMA_ABC_LEN_OFFSET + 8;
+
+/// A proof is `a || b || c`, for `a` and `c` in `G1` and `b` in `G2`.
+const PROOF_SIZE: usize = 2 * G1_COMPRESSED_SIZE + G2_COMPRESSED_SIZE;
+
+fn abort(abort_code: u64) -> SafeNativeError {
+    SafeNativeError::Abort { abort_code }
+}
+
+/// Deserialize a verifying key for `num_public_inputs` inputs.
+///
+/// The sizes are checked before the (subgroup-checking) arkworks deserialization, so its cost
+/// is bounded by the charged number of public inputs.
+fn deserialize_vk(bytes: &[u8], num_public_inputs: usize) -> SafeNativeResult<VerifyingKey<Bn254>> {
+    let len_bytes = bytes
+        .get(VK_GAMMA_ABC_LEN_OFFSET..VK_GAMMA_ABC_OFFSET)
+        .ok_or_else(|| abort(abort_codes::E_MALFORMED_VERIFYING_KEY))?;
+    let num_gamma_abc = u64::from_le_bytes(len_bytes.try_into().unwrap());
+    if num_gamma_abc != num_public_inputs as u64 + 1 {
+        return Err(abort(abort_codes::E_WRONG_NUMBER_OF_PUBLIC_INPUTS));
+    }
+    if bytes.len() != VK_GAMMA_ABC_OFFSET + (num_public_inputs + 1) * G1_COMPRESSED_SIZE {
+        return Err(abort(abort_codes::E_MALFORMED_VERIFYING_KEY));
+    }
+    VerifyingKey::<Bn254>::deserialize_compressed(bytes)
+        .map_err(|_| abort(abort_codes::E_MALFORMED_VERIFYING_KEY))
+}
+
+/***************************************************************************************************
+ * native fun verify_groth16_bn254_internal
+ *
+ *   gas cost: base_cost + per_public_input_cost * num_public_inputs
+ *
+ **************************************************************************************************/
+fn native_verify_groth16_bn254(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let proof_bytes = safely_pop_arg!(args, Vec<u8>);
+    let public_inputs = safely_pop_arg!(args, Vector).unpack_unchecked()?;
+    let vk_bytes = safely_pop_arg!(args, Vec<u8>);
+
+    context.charge(
+        GROTH16_BN254_VERIFY_BASE
+            + GROTH16_BN254_VERIFY_PER_PUBLIC_INPUT * NumArgs::new(public_inputs.len() as u64),
+    )?;
+
+    let vk = deserialize_vk(&vk_bytes, public_inputs.len())?;
+
+    if proof_bytes.len() != PROOF_SIZE {
+        return Err(abort(abort_codes::E_MALFORMED_PROOF));
+    }
+    let proof = Proof::<Bn254>::deserialize_compressed(proof_bytes.as_slice())
+        .map_err(|_| abort(abort_codes::E_MALFORMED_PROOF))?;
+
+    let public_inputs = public_inputs
+        .into_iter()
+        .map(|value| {
+            let bytes = value.value_as::<U256>()?.to_le_bytes();
+            // Unlike `from_le_bytes_mod_order`, this rejects values that are not reduced.
+            ark_bn254::Fr::deserialize_uncompressed(bytes.as_slice())
+                .map_err(|_| abort(abort_codes::E_PUBLIC_INPUT_NOT_CANONICAL))
+        })
+        .collect::<SafeNativeResult<Vec<_>>>()?;
+
+    let pvk = PreparedVerifyingKey::from(vk);
+    // `verify_proof` only errs on an input count that does not match the key, ruled out above.
+    let verified = Groth16::<Bn254>::verify_proof(&pvk, &proof, &public_inputs).unwrap_or(false);
+
+    Ok(smallvec![Value::bool(verified)])
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(
+    builder: &SafeNativeBuilder,
+) -> impl Iterator<Item = (String, NativeFunction)> + '_ {
+    let natives = [(
+        "verify_groth16_bn254_internal",
+        native_verify_groth16_bn254 as RawSafeNative,
+    )];
+
+    builder.make_named_natives(natives)
+}
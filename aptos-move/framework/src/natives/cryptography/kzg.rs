@@ -0,0 +1,108 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_pop_arg, RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeError,
+    SafeNativeResult,
+};
+use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::Zero;
+use ark_serialize::CanonicalDeserialize;
+use move_core_types::u256::U256;
+use move_vm_runtime::native_functions::NativeFunction;
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use smallvec::{smallvec, SmallVec};
+use std::collections::VecDeque;
+
+pub mod abort_codes {
+    /// Abort code when the commitment fails to deserialize (leading 0x01 == INVALID_ARGUMENT)
+    /// NOTE: This must match the code in the Move implementation
+    pub const E_MALFORMED_COMMITMENT: u64 = 0x01_0001;
+
+    /// Abort code when the opening proof fails to deserialize.
+    /// NOTE: This must match the code in the Move implementation
+    pub const E_MALFORMED_PROOF: u64 = 0x01_0002;
+
+    /// Abort code when the SRS element fails to deserialize.
+    /// NOTE: This must match the code in the Move implementation
+    pub const E_MALFORMED_SRS: u64 = 0x01_0003;
+
+    /// Abort code when the point or the value is not smaller than the BN254 scalar field modulus.
+    /// NOTE: This must match the code in the Move implementation
+    pub const E_SCALAR_NOT_CANONICAL: u64 = 0x01_0004;
+}
+
+fn deserialize_compressed<T: CanonicalDeserialize>(
+    bytes: &[u8],
+    size: usize,
+    abort_code: u64,
+) -> SafeNativeResult<T> {
+    // Exit early on a wrong size, so the deserialization cost is bounded.
+    if bytes.len() != size {
+        return Err(SafeNativeError::Abort { abort_code });
+    }
+    T::deserialize_compressed(bytes).map_err(|_| SafeNativeError::Abort { abort_code })
+}
+
+fn scalar_from_u256(value: U256) -> SafeNativeResult<Fr> {
+    Fr::deserialize_uncompressed(value.to_le_bytes().as_slice()).map_err(|_| {
+        SafeNativeError::Abort {
+            abort_code: abort_codes::E_SCALAR_NOT_CANONICAL,
+        }
+    })
+}
+
+/***************************************************************************************************
+ * native fun kzg_verify_bn254_internal
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_kzg_verify_bn254(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 5);
+
+    let srs_g2_bytes = safely_pop_arg!(args, Vec<u8>);
+    let proof_bytes = safely_pop_arg!(args, Vec<u8>);
+    let value = safely_pop_arg!(args, U256);
+    let point = safely_pop_arg!(args, U256);
+    let commitment_bytes = safely_pop_arg!(args, Vec<u8>);
+
+    context.charge(KZG_BN254_VERIFY_BASE)?;
+
+    let commitment: G1Affine =
+        deserialize_compressed(&commitment_bytes, 32, abort_codes::E_MALFORMED_COMMITMENT)?;
+    let proof: G1Affine = deserialize_compressed(&proof_bytes, 32, abort_codes::E_MALFORMED_PROOF)?;
+    let tau_g2: G2Affine = deserialize_compressed(&srs_g2_bytes, 64, abort_codes::E_MALFORMED_SRS)?;
+    let point = scalar_from_u256(point)?;
+    let value = scalar_from_u256(value)?;
+
+    // `e(C - v*G1, G2) == e(pi, tau*G2 - z*G2)`, rearranged into a single multi-pairing:
+    // `e(C - v*G1 + z*pi, -G2) * e(pi, tau*G2) == 1`.
+    let lhs =
+        (commitment.into_group() - G1Affine::generator() * value + proof * point).into_affine();
+    let result = Bn254::multi_pairing([lhs, proof], [-G2Affine::generator(), tau_g2]);
+
+    Ok(smallvec![Value::bool(result.is_zero())])
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(
+    builder: &SafeNativeBuilder,
+) -> impl Iterator<Item = (String, NativeFunction)> + '_ {
+    let natives = [(
+        "kzg_verify_bn254_internal",
+        native_kzg_verify_bn254 as RawSafeNative,
+    )];
+
+    builder.make_named_natives(natives)
+}
@@ -5,7 +5,10 @@ pub mod algebra;
 pub mod bls12381;
 pub mod bulletproofs;
 pub mod ed25519;
+pub mod groth16;
 mod helpers;
+pub mod kzg;
+pub mod modexp;
 pub mod multi_ed25519;
 pub mod ristretto255;
 pub mod ristretto255_point;
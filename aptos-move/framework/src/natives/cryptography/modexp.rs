@@ -0,0 +1,110 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_gas_algebra::NumArgs;
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_pop_arg, RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeResult,
+};
+use move_vm_runtime::native_functions::NativeFunction;
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use num_bigint::BigUint;
+use num_traits::Zero;
+use smallvec::{smallvec, SmallVec};
+use std::collections::VecDeque;
+
+/// The multiplication complexity of EIP-198, for operands of `x` bytes.
+fn mult_complexity(x: u128) -> u128 {
+    if x <= 64 {
+        x * x
+    } else if x <= 1024 {
+        x * x / 4 + 96 * x - 3072
+    } else {
+        (x * x / 16 + 480 * x).saturating_sub(199_680)
+    }
+}
+
+/// The adjusted exponent length of EIP-198, i.e., roughly the bit length of the exponent.
+fn adjusted_exponent_length(exp: &[u8]) -> u128 {
+    let head = &exp[..exp.len().min(32)];
+    let highest_bit_in_head = BigUint::from_bytes_be(head).bits().saturating_sub(1) as u128;
+    let tail_len = exp.len().saturating_sub(32) as u128;
+    8 * tail_len + highest_bit_in_head
+}
+
+/// The number of exponent bits that the Montgomery setup of an odd modulus costs about as much as.
+/// Charged on top of the exponent, so that a large modulus with a tiny exponent is not underpriced.
+const SETUP_EXPONENT_BITS: u128 = 96;
+
+/// The number of square-and-multiply iterations charged for: the adjusted exponent length (at
+/// least 1, as in EIP-198) plus the setup.
+fn charged_iterations(exp: &[u8]) -> u128 {
+    adjusted_exponent_length(exp).max(1) + SETUP_EXPONENT_BITS
+}
+
+/// The EIP-198 cost of `iterations` multiplications of operands of `x` bytes, before the Aptos
+/// conversion.
+fn eip198_gas_units(x: u128, iterations: u128) -> u64 {
+    let units = mult_complexity(x).saturating_mul(iterations) / 20;
+    u64::try_from(units).unwrap_or(u64::MAX)
+}
+
+/***************************************************************************************************
+ * native fun modexp_internal
+ *
+ *   gas cost: base_cost + per_exponent_bit * iterations
+ *                       + per_eip198_gas_unit * eip198_gas_units(max(|base|, |modulus|), iterations)
+ *
+ *   where iterations = max(adjusted_exponent_length(exp), 1) + SETUP_EXPONENT_BITS.
+ *
+ *   The per-exponent-bit term does not depend on the operand sizes: with a tiny base and modulus,
+ *   the EIP-198 term rounds to almost nothing, while each exponent bit still costs a few BigUint
+ *   operations.
+ *
+ **************************************************************************************************/
+fn native_modexp(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let modulus = safely_pop_arg!(args, Vec<u8>);
+    let exp = safely_pop_arg!(args, Vec<u8>);
+    let base = safely_pop_arg!(args, Vec<u8>);
+
+    // Charge before parsing, so that the cost of large operands is paid upfront.
+    let iterations = charged_iterations(&exp);
+    let units = eip198_gas_units(base.len().max(modulus.len()) as u128, iterations);
+    let iterations = u64::try_from(iterations).unwrap_or(u64::MAX);
+    context.charge(
+        MODEXP_BASE
+            + MODEXP_PER_EXPONENT_BIT * NumArgs::new(iterations)
+            + MODEXP_PER_EIP198_GAS_UNIT * NumArgs::new(units),
+    )?;
+
+    let modulus_int = BigUint::from_bytes_be(&modulus);
+    let mut output = vec![0u8; modulus.len()];
+    if !modulus_int.is_zero() {
+        let result = BigUint::from_bytes_be(&base)
+            .modpow(&BigUint::from_bytes_be(&exp), &modulus_int)
+            .to_bytes_be();
+        // The result is smaller than the modulus, so it fits after left-padding with zeros.
+        output[modulus.len() - result.len()..].copy_from_slice(&result);
+    }
+
+    Ok(smallvec![Value::vector_u8(output)])
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(
+    builder: &SafeNativeBuilder,
+) -> impl Iterator<Item = (String, NativeFunction)> + '_ {
+    let natives = [("modexp_internal", native_modexp as RawSafeNative)];
+
+    builder.make_named_natives(natives)
+}
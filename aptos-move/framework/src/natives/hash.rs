@@ -4,13 +4,20 @@
 use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
 use aptos_native_interface::{
     safely_assert_eq, safely_pop_arg, RawSafeNative, SafeNativeBuilder, SafeNativeContext,
-    SafeNativeResult,
+    SafeNativeError, SafeNativeResult,
+};
+use ark_ff::{BigInteger, PrimeField};
+use move_binary_format::errors::PartialVMError;
+use move_core_types::{
+    gas_algebra::{InternalGas, InternalGasPerByte, NumBytes},
+    u256::U256,
+    vm_status::StatusCode,
 };
-use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
 use move_vm_runtime::native_functions::NativeFunction;
 use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
 use ripemd::Digest as OtherDigest;
 use sha2::Digest;
+use sha2_0_10_6::digest::generic_array::GenericArray;
 use smallvec::{smallvec, SmallVec};
 use std::{collections::VecDeque, hash::Hasher};
 use tiny_keccak::{Hasher as KeccakHasher, Keccak};
@@ -103,6 +110,40 @@ fn native_sha3_512(
     Ok(smallvec![Value::vector_u8(output)])
 }
 
+/***************************************************************************************************
+ * native fun keccak_to_bn254_fr_internal
+ *
+ *   gas cost: base_cost + unit_cost * data_length
+ *
+ **************************************************************************************************/
+fn native_keccak_to_bn254_fr(
+    context: &mut SafeNativeContext,
+    mut _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    safely_assert_eq!(_ty_args.len(), 0);
+    safely_assert_eq!(args.len(), 1);
+
+    let bytes = safely_pop_arg!(args, Vec<u8>);
+
+    context.charge(
+        HASH_KECCAK_TO_BN254_FR_BASE
+            + HASH_KECCAK_TO_BN254_FR_PER_BYTE * NumBytes::new(bytes.len() as u64),
+    )?;
+
+    let mut hasher = Keccak::v256();
+    hasher.update(&bytes);
+    let mut digest = [0u8; 32];
+    hasher.finalize(&mut digest);
+
+    // Same as `uint256(keccak256(bytes)) % r` in Solidity: the digest is read as a big-endian integer.
+    let scalar = ark_bn254::Fr::from_be_bytes_mod_order(&digest);
+    let mut output = [0u8; 32];
+    output.copy_from_slice(&scalar.into_bigint().to_bytes_le());
+
+    Ok(smallvec![Value::u256(U256::from_le_bytes(&output))])
+}
+
 #[derive(Debug, Clone)]
 pub struct Blake2B256HashGasParameters {
     pub base: InternalGas,
@@ -156,6 +197,52 @@ fn native_ripemd160(
     Ok(smallvec![Value::vector_u8(output)])
 }
 
+/// The SHA-256 compression function (section 6.2.2 of FIPS 180-4), applied to a single block of
+/// 16 words, which `sha2` takes as their 64 big-endian bytes.
+fn sha256_compress(state: &mut [u32; 8], block: &[u32; 16]) {
+    let mut bytes = GenericArray::default();
+    for (chunk, word) in bytes.chunks_exact_mut(4).zip(block) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    sha2_0_10_6::compress256(state, &[bytes]);
+}
+
+/***************************************************************************************************
+ * native fun sha256_compress_internal
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+fn native_sha256_compress(
+    context: &mut SafeNativeContext,
+    mut _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    safely_assert_eq!(_ty_args.len(), 0);
+    safely_assert_eq!(args.len(), 2);
+
+    let block = safely_pop_arg!(args, Vec<u32>);
+    let state = safely_pop_arg!(args, Vec<u32>);
+
+    // The lengths are checked by the Move caller.
+    let block: [u32; 16] = block.try_into().map_err(|_| {
+        SafeNativeError::InvariantViolation(PartialVMError::new(
+            StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR,
+        ))
+    })?;
+    let mut state: [u32; 8] = state.try_into().map_err(|_| {
+        SafeNativeError::InvariantViolation(PartialVMError::new(
+            StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR,
+        ))
+    })?;
+
+    context.charge(HASH_SHA256_COMPRESS_BASE)?;
+
+    sha256_compress(&mut state, &block);
+
+    Ok(smallvec![Value::vector_u32(state)])
+}
+
 /***************************************************************************************************
  * module
  *
@@ -170,6 +257,8 @@ pub fn make_all(
         ("sha3_512_internal", native_sha3_512),
         ("ripemd160_internal", native_ripemd160),
         ("blake2b_256_internal", native_blake2b_256),
+        ("keccak_to_bn254_fr_internal", native_keccak_to_bn254_fr),
+        ("sha256_compress_internal", native_sha256_compress),
     ];
 
     builder.make_named_natives(natives)
@@ -54,10 +54,17 @@ pub fn all_natives(
     add_natives_from_module!("create_signer", create_signer::make_all(builder));
     add_natives_from_module!("ed25519", ed25519::make_all(builder));
     add_natives_from_module!("crypto_algebra", cryptography::algebra::make_all(builder));
+    add_natives_from_module!(
+        "bn254_algebra",
+        cryptography::algebra::bn254::make_all(builder)
+    );
     add_natives_from_module!("genesis", create_signer::make_all(builder));
     add_natives_from_module!("multi_ed25519", multi_ed25519::make_all(builder));
     add_natives_from_module!("bls12381", cryptography::bls12381::make_all(builder));
     add_natives_from_module!("secp256k1", cryptography::secp256k1::make_all(builder));
+    add_natives_from_module!("groth16", cryptography::groth16::make_all(builder));
+    add_natives_from_module!("kzg", cryptography::kzg::make_all(builder));
+    add_natives_from_module!("modexp", cryptography::modexp::make_all(builder));
     add_natives_from_module!("aptos_hash", hash::make_all(builder));
     add_natives_from_module!(
         "ristretto255",
@@ -12,7 +12,7 @@ use crate::bench_utils::{
     bench_function_sub,
 };
 use aptos_crypto::test_utils::random_bytes;
-use ark_bls12_381::{Fq12, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use ark_bls12_381::{Fq, Fq12, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
 use ark_ec::{
     hashing::HashToCurve, pairing::Pairing, short_weierstrass::Projective, AffineRepr, CurveGroup,
     Group,
@@ -122,6 +122,37 @@ fn bench_group(c: &mut Criterion) {
             },
         )
     });
+
+    group.bench_function("fq_add", bench_function_add::<Fq>);
+    group.bench_function("fq_clone", bench_function_clone::<Fq>);
+    group.bench_function("fq_deser", bench_function_deser_uncomp::<Fq>);
+    group.bench_function("fq_div", bench_function_div::<Fq>);
+    group.bench_function("fq_double", bench_function_double::<Fq>);
+    group.bench_function("fq_eq", bench_function_eq::<Fq>);
+    group.bench_function("fq_from_u64", bench_function_from_u64::<Fq>);
+    group.bench_function("fq_inv", bench_function_inv::<Fq>);
+    group.bench_function("fq_mul", bench_function_mul::<Fq>);
+    group.bench_function("fq_neg", bench_function_neg::<Fq>);
+    group.bench_function("fq_pow_u256", bench_function_pow_u256::<Fq>);
+    group.bench_function("fq_serialize", bench_function_serialize_uncomp::<Fq>);
+    group.bench_function("fq_square", bench_function_square::<Fq>);
+    group.bench_function("fq_sub", bench_function_sub::<Fq>);
+
+    group.bench_function("fq2_add", bench_function_add::<Fq2>);
+    group.bench_function("fq2_clone", bench_function_clone::<Fq2>);
+    group.bench_function("fq2_deser", bench_function_deser_uncomp::<Fq2>);
+    group.bench_function("fq2_div", bench_function_div::<Fq2>);
+    group.bench_function("fq2_double", bench_function_double::<Fq2>);
+    group.bench_function("fq2_eq", bench_function_eq::<Fq2>);
+    group.bench_function("fq2_from_u64", bench_function_from_u64::<Fq2>);
+    group.bench_function("fq2_inv", bench_function_inv::<Fq2>);
+    group.bench_function("fq2_mul", bench_function_mul::<Fq2>);
+    group.bench_function("fq2_neg", bench_function_neg::<Fq2>);
+    group.bench_function("fq2_pow_u256", bench_function_pow_u256::<Fq2>);
+    group.bench_function("fq2_serialize", bench_function_serialize_uncomp::<Fq2>);
+    group.bench_function("fq2_square", bench_function_square::<Fq2>);
+    group.bench_function("fq2_sub", bench_function_sub::<Fq2>);
+
     group.bench_function("fq12_add", bench_function_add::<Fq12>);
     group.bench_function("fq12_clone", bench_function_clone::<Fq12>);
     group.bench_function("fq12_deser", bench_function_deser_uncomp::<Fq12>);
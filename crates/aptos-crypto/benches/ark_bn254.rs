@@ -13,7 +13,7 @@ use crate::bench_utils::{
 };
 use ark_bn254::{Bn254, Fq, Fq12, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
 use ark_ec::{pairing::Pairing, short_weierstrass::Projective, AffineRepr, CurveGroup, Group};
-use ark_ff::{UniformRand, Zero};
+use ark_ff::{CyclotomicMultSubgroup, Field, UniformRand, Zero};
 use ark_groth16::Groth16;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::test_rng;
@@ -92,6 +92,19 @@ fn bench_group(c: &mut Criterion) {
     group.bench_function("fq12_mul", bench_function_mul::<Fq12>);
     group.bench_function("fq12_neg", bench_function_neg::<Fq12>);
     group.bench_function("fq12_pow_u256", bench_function_pow_u256::<Fq12>);
+    group.bench_function("fq12_cyclotomic_exp_u64", move |b| {
+        b.iter_with_setup(
+            // Pairing outputs are in the cyclotomic subgroup.
+            || Bn254::pairing(rand!(G1Affine), rand!(G2Affine)).0,
+            |element| {
+                // The native checks the subgroup membership first, as `x^(q^4) * x == x^(q^2)`.
+                let element_q2 = element.frobenius_map(2);
+                let _in_subgroup = element_q2.frobenius_map(2) * element == element_q2;
+                // The gas is flat, so time the `u64` with the most non-zero NAF digits.
+                let _res = element.cyclotomic_exp([0x5555_5555_5555_5555u64]);
+            },
+        )
+    });
     group.bench_function("fq12_serialize", bench_function_serialize_uncomp::<Fq12>);
     group.bench_function("fq12_square", bench_function_square::<Fq12>);
     group.bench_function("fq12_sub", bench_function_sub::<Fq12>);
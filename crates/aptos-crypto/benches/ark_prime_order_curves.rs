@@ -0,0 +1,263 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Benches for the prime-order curves of the algebra module: secp256k1, secp256r1, Grumpkin, Pallas and Vesta.
+//! Each curve gets its own group, so that results land in `target/criterion/ark_<curve>/<op>`,
+//! like the BN254 and BLS12-381 ones.
+
+#[macro_use]
+extern crate criterion;
+
+use crate::bench_utils::{
+    bench_function_add, bench_function_clone, bench_function_deser_comp,
+    bench_function_deser_uncomp, bench_function_div, bench_function_double, bench_function_eq,
+    bench_function_from_u64, bench_function_inv, bench_function_mul, bench_function_neg,
+    bench_function_pow_u256, bench_function_serialize_uncomp, bench_function_square,
+    bench_function_sub,
+};
+use ark_ec::CurveGroup;
+use ark_serialize::CanonicalSerialize;
+use ark_std::{test_rng, UniformRand};
+use criterion::Criterion;
+use std::ops::Mul;
+
+mod bench_utils;
+
+/// There is no arkworks 0.4 release of Grumpkin, so bench the curve defined by the framework natives.
+#[path = "../../../aptos-move/framework/src/natives/cryptography/algebra/grumpkin.rs"]
+#[allow(dead_code, unused_imports)]
+mod grumpkin;
+
+/// The secp256k1 and secp256r1 points use the SEC1 encoding of the framework natives.
+#[path = "../../../aptos-move/framework/src/natives/cryptography/algebra/sec1.rs"]
+mod sec1;
+
+/// The Pallas and Vesta points use the `pasta_curves` encoding of the framework natives.
+#[path = "../../../aptos-move/framework/src/natives/cryptography/algebra/pasta.rs"]
+mod pasta;
+
+macro_rules! rand {
+    ($typ:ty) => {{
+        <$typ>::rand(&mut test_rng())
+    }};
+}
+
+macro_rules! serialize {
+    ($obj:expr, $method:ident) => {{
+        let mut buf = vec![];
+        $obj.$method(&mut buf).unwrap();
+        buf
+    }};
+}
+
+/// Bench the field operations that have a gas parameter, as `<name>_<op>`.
+macro_rules! bench_field {
+    ($group:expr, $name:literal, $field:ty) => {{
+        $group.bench_function(concat!($name, "_add"), bench_function_add::<$field>);
+        $group.bench_function(concat!($name, "_clone"), bench_function_clone::<$field>);
+        $group.bench_function(
+            concat!($name, "_deser"),
+            bench_function_deser_uncomp::<$field>,
+        );
+        $group.bench_function(concat!($name, "_div"), bench_function_div::<$field>);
+        $group.bench_function(concat!($name, "_double"), bench_function_double::<$field>);
+        $group.bench_function(concat!($name, "_eq"), bench_function_eq::<$field>);
+        $group.bench_function(
+            concat!($name, "_from_u64"),
+            bench_function_from_u64::<$field>,
+        );
+        $group.bench_function(concat!($name, "_inv"), bench_function_inv::<$field>);
+        $group.bench_function(concat!($name, "_mul"), bench_function_mul::<$field>);
+        $group.bench_function(concat!($name, "_neg"), bench_function_neg::<$field>);
+        $group.bench_function(
+            concat!($name, "_pow_u256"),
+            bench_function_pow_u256::<$field>,
+        );
+        $group.bench_function(
+            concat!($name, "_serialize"),
+            bench_function_serialize_uncomp::<$field>,
+        );
+        $group.bench_function(concat!($name, "_square"), bench_function_square::<$field>);
+        $group.bench_function(concat!($name, "_sub"), bench_function_sub::<$field>);
+    }};
+}
+
+/// Bench the point encoding of the arkworks formats, as `g1_affine_<op>`.
+/// Compressed deserialization computes a square root in the base field, whose cost depends on its 2-adicity.
+macro_rules! bench_g1_ark_codec {
+    ($group:expr, $affine:ty) => {{
+        $group.bench_function("g1_affine_deser_comp", bench_function_deser_comp::<$affine>);
+        $group.bench_function(
+            "g1_affine_deser_uncomp",
+            bench_function_deser_uncomp::<$affine>,
+        );
+
+        $group.bench_function("g1_affine_serialize_comp", move |b| {
+            b.iter_with_setup(
+                || rand!($affine),
+                |p_affine| {
+                    let _buf = serialize!(p_affine, serialize_compressed);
+                },
+            )
+        });
+
+        $group.bench_function("g1_affine_serialize_uncomp", move |b| {
+            b.iter_with_setup(
+                || rand!($affine),
+                |p_affine| {
+                    let _buf = serialize!(p_affine, serialize_uncompressed);
+                },
+            )
+        });
+    }};
+}
+
+/// Bench a point encoding implemented by the framework natives, e.g., SEC1, as `g1_affine_<op>`.
+/// `$encode` and `$decode` take a `compressed` flag, and `$decode` runs every check the native relies on.
+macro_rules! bench_g1_codec {
+    ($group:expr, $affine:ty, $encode:path, $decode:path) => {{
+        for (suffix, compressed) in [("comp", true), ("uncomp", false)] {
+            $group.bench_function(format!("g1_affine_deser_{}", suffix), move |b| {
+                b.iter_with_setup(
+                    || $encode(&rand!($affine), compressed),
+                    |buf| {
+                        let _p: $affine = $decode(buf.as_slice(), compressed).unwrap();
+                    },
+                )
+            });
+
+            $group.bench_function(format!("g1_affine_serialize_{}", suffix), move |b| {
+                b.iter_with_setup(
+                    || rand!($affine),
+                    |p_affine| {
+                        let _buf = $encode(&p_affine, compressed);
+                    },
+                )
+            });
+        }
+    }};
+}
+
+/// Bench the other group operations that have a gas parameter, as `g1_<op>`.
+macro_rules! bench_g1 {
+    ($group:expr, $projective:ty, $scalar:ty) => {{
+        $group.bench_function("g1_proj_add", move |b| {
+            b.iter_with_setup(
+                || (rand!($projective), rand!($projective)),
+                |(p, q)| {
+                    let _res = p + q;
+                },
+            )
+        });
+
+        $group.bench_function("g1_proj_double", move |b| {
+            b.iter_with_setup(
+                || rand!($projective),
+                |p| {
+                    let _q = ark_ec::Group::double(&p);
+                },
+            )
+        });
+
+        $group.bench_function("g1_proj_eq", move |b| {
+            b.iter_with_setup(
+                || {
+                    let p = rand!($projective);
+                    let q = p;
+                    (p, q)
+                },
+                |(p, q)| {
+                    let _res = p == q;
+                },
+            )
+        });
+
+        $group.bench_function("g1_proj_scalar_mul", move |b| {
+            b.iter_with_setup(
+                || (rand!($projective), rand!($scalar)),
+                |(p, k)| {
+                    let _q = p.mul(k);
+                },
+            )
+        });
+
+        $group.bench_function("g1_proj_sub", move |b| {
+            b.iter_with_setup(
+                || (rand!($projective), rand!($projective)),
+                |(p, q)| {
+                    let _r = p - q;
+                },
+            )
+        });
+
+        $group.bench_function("g1_proj_to_affine", move |b| {
+            b.iter_with_setup(
+                || rand!($projective),
+                |p_proj| {
+                    let _ = p_proj.into_affine();
+                },
+            )
+        });
+    }};
+}
+
+fn bench_group(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ark_secp256k1");
+    bench_field!(group, "fq", ark_secp256k1::Fq);
+    bench_field!(group, "fr", ark_secp256k1::Fr);
+    bench_g1_codec!(
+        group,
+        ark_secp256k1::Affine,
+        sec1::sec1_serialize,
+        sec1::sec1_deserialize
+    );
+    bench_g1!(group, ark_secp256k1::Projective, ark_secp256k1::Fr);
+    group.finish();
+
+    let mut group = c.benchmark_group("ark_secp256r1");
+    bench_field!(group, "fq", ark_secp256r1::Fq);
+    bench_field!(group, "fr", ark_secp256r1::Fr);
+    bench_g1_codec!(
+        group,
+        ark_secp256r1::Affine,
+        sec1::sec1_serialize,
+        sec1::sec1_deserialize
+    );
+    bench_g1!(group, ark_secp256r1::Projective, ark_secp256r1::Fr);
+    group.finish();
+
+    // The Grumpkin fields are the BN254 ones, which the `ark_bn254` benches cover.
+    let mut group = c.benchmark_group("ark_grumpkin");
+    bench_g1_ark_codec!(group, grumpkin::Affine);
+    bench_g1!(group, grumpkin::Projective, grumpkin::Fr);
+    group.finish();
+
+    let mut group = c.benchmark_group("ark_pallas");
+    bench_field!(group, "fq", ark_pallas::Fq);
+    bench_field!(group, "fr", ark_pallas::Fr);
+    bench_g1_codec!(
+        group,
+        ark_pallas::Affine,
+        pasta::pasta_serialize,
+        pasta::pasta_deserialize
+    );
+    bench_g1!(group, ark_pallas::Projective, ark_pallas::Fr);
+    group.finish();
+
+    // The Vesta fields are the Pallas ones swapped, which the `ark_pallas` benches cover.
+    let mut group = c.benchmark_group("ark_vesta");
+    bench_g1_codec!(
+        group,
+        ark_vesta::Affine,
+        pasta::pasta_serialize,
+        pasta::pasta_deserialize
+    );
+    bench_g1!(group, ark_vesta::Projective, ark_vesta::Fr);
+    group.finish();
+}
+
+criterion_group!(
+    name = ark_prime_order_curves_benches;
+    config = Criterion::default();
+    targets = bench_group);
+criterion_main!(ark_prime_order_curves_benches);
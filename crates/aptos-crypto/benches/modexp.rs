@@ -0,0 +1,76 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#[macro_use]
+extern crate criterion;
+
+use aptos_crypto::test_utils::random_bytes;
+use criterion::{measurement::Measurement, BenchmarkGroup, BenchmarkId, Criterion, Throughput};
+use num_bigint::BigUint;
+use rand::thread_rng;
+
+/// Modulus sizes in bytes, from tiny to RSA-4096.
+const MODULUS_LENS: [usize; 5] = [8, 32, 256, 1024, 4096];
+
+/// Exponent sizes in bytes: a single byte (setup-dominated), a 256-bit one, and a long one.
+const EXPONENT_LENS: [usize; 3] = [1, 32, 1024];
+
+/// Benchmarks `BigUint::modpow`, as called by the `aptos_std::modexp` native, over small and large
+/// moduli and short and long exponents. (Used for gas estimation.)
+fn bench_group(c: &mut Criterion) {
+    let mut group = c.benchmark_group("modexp");
+
+    group.sample_size(10);
+
+    for mod_len in MODULUS_LENS {
+        for exp_len in EXPONENT_LENS {
+            modexp(&mut group, mod_len, exp_len, true);
+            modexp(&mut group, mod_len, exp_len, false);
+        }
+    }
+
+    group.finish();
+}
+
+/// Odd moduli take the Montgomery path of `modpow`, which is faster per exponent bit but has a
+/// setup cost; even moduli take the plain square-and-multiply path.
+fn modexp<M: Measurement>(g: &mut BenchmarkGroup<M>, mod_len: usize, exp_len: usize, odd: bool) {
+    let mut rng = thread_rng();
+
+    g.throughput(Throughput::Elements(1));
+
+    let name = if odd { "odd_modulus" } else { "even_modulus" };
+    g.bench_function(
+        BenchmarkId::new(name, format!("{}x{}", mod_len, exp_len)),
+        move |b| {
+            b.iter_with_setup(
+                || {
+                    // Set the top bits, so that the operands have the intended size.
+                    let mut base = random_bytes(&mut rng, mod_len);
+                    let mut exp = random_bytes(&mut rng, exp_len);
+                    let mut modulus = random_bytes(&mut rng, mod_len);
+                    base[0] |= 0x80;
+                    exp[0] |= 0x80;
+                    modulus[0] |= 0x80;
+                    if odd {
+                        modulus[mod_len - 1] |= 1;
+                    } else {
+                        modulus[mod_len - 1] &= 0xFE;
+                    }
+                    (
+                        BigUint::from_bytes_be(&base),
+                        BigUint::from_bytes_be(&exp),
+                        BigUint::from_bytes_be(&modulus),
+                    )
+                },
+                |(base, exp, modulus)| base.modpow(&exp, &modulus),
+            )
+        },
+    );
+}
+
+criterion_group!(
+    name = modexp_benches;
+    config = Criterion::default();
+    targets = bench_group);
+criterion_main!(modexp_benches);
@@ -0,0 +1,46 @@
+#!/usr/bin/env python3
+
+'''
+This module automated the steps to
+calculate gas parameters for the BLS12-381 `Fq` and `Fq2` structures of `crypto_algebra.move` from benchmarking results,
+then update the gas parameter definitions in rust.
+
+Unless `--gas_per_ns` is given, it is calibrated against the BLS12-381 parameters, which were generated on another machine:
+it is the median ratio of the BLS12-381 parameters to the `ark_bls12_381` bench times of the same run.
+'''
+
+import argparse
+import load_bench_ns
+from pathlib import Path
+from time import time
+from update_prime_order_curves_gas_params import CONSTANT_OPS, FIELD_OPS, TARGET_GAS_VERSION, calibrate_gas_per_ns, prettify_number, section_bounds
+
+def get_algebra_lines(gas_per_ns):
+    nanoseconds = {}
+    for field in ['fq', 'fq2']:
+        for op in FIELD_OPS:
+            if op in CONSTANT_OPS:
+                nanoseconds[f'ark_bls12_381_{field}_{op}'] = 1
+            else:
+                nanoseconds[f'ark_bls12_381_{field}_{op}'] = load_bench_ns.main(f'target/criterion/ark_bls12_381/{field}_{op}')
+    gas_param_entries = { k:int(gas_per_ns*v) for k,v in nanoseconds.items()}
+    lines = [f'        [algebra_{k}: InternalGas, {{ {TARGET_GAS_VERSION}.. => "algebra.{k}" }}, {prettify_number(qty)}],' for k,qty in sorted(gas_param_entries.items())]
+    return lines
+
+def main(gas_per_ns):
+    path = Path(PATH_STR)
+    lines = path.read_text().split('\n')
+    if gas_per_ns is None:
+        gas_per_ns = calibrate_gas_per_ns(lines, 'BLS12-381', 'ark_bls12_381')
+    line_id_begin, line_id_end = section_bounds(lines, 'BLS12-381 base field')
+    generator_note_line = f'        // Generated at time {time()} by `scripts/algebra-gas/update_bls12381_base_field_gas_params.py` with gas_per_ns={gas_per_ns}.'
+    new_lines = lines[:line_id_begin+1] + [generator_note_line] + get_algebra_lines(gas_per_ns) + lines[line_id_end:]
+    path.write_text('\n'.join(new_lines))
+
+PATH_STR = 'aptos-move/aptos-gas-schedule/src/gas_schedule/aptos_framework.rs'
+if __name__=='__main__':
+    parser = argparse.ArgumentParser(
+        description=f'Generate gas parameters for the BLS12-381 base field structures of the algebra module in `{PATH_STR}`.')
+    parser.add_argument('--gas_per_ns', type=float)
+    args = parser.parse_args()
+    main(args.gas_per_ns)
@@ -0,0 +1,125 @@
+#!/usr/bin/env python3
+
+'''
+This module automated the steps to
+calculate gas parameters for the prime-order curve structures of `crypto_algebra.move` from benchmarking results,
+then update the gas parameter definitions in rust.
+
+The benches are in `crates/aptos-crypto/benches/ark_prime_order_curves.rs`.
+Unless `--gas_per_ns` is given, it is calibrated against the BN254 parameters, which were generated on another machine:
+it is the median ratio of the BN254 parameters to the `ark_bn254` bench times of the same run.
+'''
+
+import argparse
+import load_bench_ns
+import re
+from pathlib import Path
+from statistics import median
+from time import time
+
+# Typically you are making a new version of gas schedule,
+# so this should be larger than `LATEST_GAS_FEATURE_VERSION` in `aptos-move/aptos-gas/src/gas_meter.rs`.
+TARGET_GAS_VERSION = 'RELEASE_V1_14'
+
+FIELD_OPS = ['add', 'deser', 'div', 'eq', 'from_u64', 'inv', 'mul', 'neg', 'one', 'serialize', 'square', 'sub', 'zero']
+G1_OPS = [
+    'affine_deser_comp', 'affine_deser_uncomp', 'affine_serialize_comp', 'affine_serialize_uncomp',
+    'proj_add', 'proj_double', 'proj_eq', 'proj_generator', 'proj_infinity', 'proj_neg', 'proj_scalar_mul',
+    'proj_sub', 'proj_to_affine',
+]
+# Operations that return a constant or only flip a sign, charged as 1ns like in `update_bn254_algebra_gas_params.py`.
+CONSTANT_OPS = {'one', 'zero', 'proj_generator', 'proj_infinity', 'proj_neg'}
+
+def bench(group, prefix):
+    return ('bench', group, prefix)
+
+def reuse(param_prefix):
+    return ('reuse', param_prefix)
+
+# For each section, the gas parameter prefixes and where their values come from:
+# either a bench group and prefix, or the parameters of an identical type that is already priced.
+SECTIONS = {
+    'secp256k1': [('fq', bench('ark_secp256k1', 'fq')), ('fr', bench('ark_secp256k1', 'fr')), ('g1', bench('ark_secp256k1', 'g1'))],
+    'secp256r1': [('fq', bench('ark_secp256r1', 'fq')), ('fr', bench('ark_secp256r1', 'fr')), ('g1', bench('ark_secp256r1', 'g1'))],
+    'Grumpkin': [('fq', reuse('ark_bn254_fr')), ('fr', reuse('ark_bn254_fq')), ('g1', bench('ark_grumpkin', 'g1'))],
+    'Pallas': [('fq', bench('ark_pallas', 'fq')), ('fr', bench('ark_pallas', 'fr')), ('g1', bench('ark_pallas', 'g1'))],
+    'Vesta': [('fq', bench('ark_pallas', 'fr')), ('fr', bench('ark_pallas', 'fq')), ('g1', bench('ark_vesta', 'g1'))],
+}
+
+# Notes to keep under the generator note of a section.
+NOTES = {
+    'Grumpkin': ['Grumpkin `Fq` and `Fr` are the BN254 `Fr` and `Fq` types, so their values are reused as is.'],
+    'Vesta': ['Vesta `Fq` and `Fr` are the Pallas `Fr` and `Fq` fields, so they are generated from the `ark_pallas` benches.'],
+}
+
+def prettify_number(x:int) -> str:
+    s = str(x)
+    n = len(s)
+    b = n % 3
+    chunks_0 = [s[:b]] if b>=1 else []
+    chunks = chunks_0 + [s[i:i+3] for i in range(b,n,3)]
+    return '_'.join(chunks)
+
+def section_bounds(lines, name):
+    striped_lines = [line.strip() for line in lines]
+    line_id_begin = striped_lines.index(f'// {name} algebra gas parameters begin.')
+    line_id_end = striped_lines.index(f'// {name} algebra gas parameters end.')
+    return line_id_begin, line_id_end
+
+def calibrate_gas_per_ns(lines, reference_section='BN254', bench_group='ark_bn254'):
+    '''Return the median ratio of the gas parameters in `reference_section` to the times of the matching `bench_group` benches.'''
+    line_id_begin, line_id_end = section_bounds(lines, reference_section)
+    ratios = []
+    for line in lines[line_id_begin+1:line_id_end]:
+        match = re.search(rf'\[algebra_{bench_group}_(\w+): \w+, \{{.*\}}, ([\d_]+)\],', line)
+        if match is None: continue
+        ns = load_bench_ns.main(f'target/criterion/{bench_group}/{match.group(1)}')
+        if ns is None: continue
+        ratios.append(int(match.group(2).replace('_', '')) / ns)
+    return median(ratios)
+
+def load_param_values(lines):
+    values = {}
+    for line in lines:
+        match = re.search(r'\[algebra_(\w+): \w+, \{.*\}, ([\d_]+)\],', line)
+        if match is None: continue
+        values[match.group(1)] = int(match.group(2).replace('_', ''))
+    return values
+
+def get_algebra_lines(name, gas_per_ns, param_values):
+    gas_param_entries = {}
+    for param_prefix, source in SECTIONS[name]:
+        ops = G1_OPS if param_prefix == 'g1' else FIELD_OPS
+        for op in ops:
+            key = f'ark_{name.lower()}_{param_prefix}_{op}'
+            if source[0] == 'reuse':
+                gas_param_entries[key] = param_values[f'{source[1]}_{op}']
+            elif op in CONSTANT_OPS:
+                gas_param_entries[key] = int(gas_per_ns)
+            else:
+                _, bench_group, bench_prefix = source
+                ns = load_bench_ns.main(f'target/criterion/{bench_group}/{bench_prefix}_{op}')
+                gas_param_entries[key] = int(gas_per_ns*ns)
+    lines = [f'        [algebra_{k}: InternalGas, {{ {TARGET_GAS_VERSION}.. => "algebra.{k}" }}, {prettify_number(qty)}],' for k,qty in sorted(gas_param_entries.items())]
+    return lines
+
+def main(gas_per_ns):
+    path = Path(PATH_STR)
+    lines = path.read_text().split('\n')
+    if gas_per_ns is None:
+        gas_per_ns = calibrate_gas_per_ns(lines)
+    param_values = load_param_values(lines)
+    for name in SECTIONS:
+        line_id_begin, line_id_end = section_bounds(lines, name)
+        generator_note_line = f'        // Generated at time {time()} by `scripts/algebra-gas/update_prime_order_curves_gas_params.py` with gas_per_ns={gas_per_ns}.'
+        note_lines = [f'        // {note}' for note in NOTES.get(name, [])]
+        lines = lines[:line_id_begin+1] + [generator_note_line] + note_lines + get_algebra_lines(name, gas_per_ns, param_values) + lines[line_id_end:]
+    path.write_text('\n'.join(lines))
+
+PATH_STR = 'aptos-move/aptos-gas-schedule/src/gas_schedule/aptos_framework.rs'
+if __name__=='__main__':
+    parser = argparse.ArgumentParser(
+        description=f'Generate gas parameters for the prime-order curves of the algebra module in `{PATH_STR}`.')
+    parser.add_argument('--gas_per_ns', type=float)
+    args = parser.parse_args()
+    main(args.gas_per_ns)
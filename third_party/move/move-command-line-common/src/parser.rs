@@ -296,7 +296,7 @@ impl<'a, I: Iterator<Item = (ValueToken, &'a str)>> Parser<'a, ValueToken, I> {
                         Ok((field, value))
                     },
                     ValueToken::Comma,
-                    ValueToken::RBracket,
+                    ValueToken::RBrace,
                     true,
                 )?;
                 self.advance(ValueToken::RBrace)?;
@@ -510,6 +510,30 @@ mod tests {
                 "x\"deadbeef\"",
                 V::Vector(vec![V::U8(0xDE), V::U8(0xAD), V::U8(0xBE), V::U8(0xEF)]),
             ),
+            (
+                "vector[x\"01\",b\"hi\"]",
+                V::Vector(vec![
+                    V::Vector(vec![V::U8(0x01)]),
+                    V::Vector(vec![V::U8(b'h'), V::U8(b'i')]),
+                ]),
+            ),
+            (
+                "0x2::M::S{f:1u8,g:true}",
+                V::Struct(
+                    ParsedAddress::Numerical(NumericalAddress::new(
+                        AccountAddress::from_hex_literal("0x2")
+                            .unwrap()
+                            .into_bytes(),
+                        crate::parser::NumberFormat::Hex,
+                    )),
+                    "M".to_owned(),
+                    "S".to_owned(),
+                    std::collections::BTreeMap::from([
+                        ("f".to_owned(), V::U8(1)),
+                        ("g".to_owned(), V::Bool(true)),
+                    ]),
+                ),
+            ),
         ];
 
         for (s, expected) in cases {
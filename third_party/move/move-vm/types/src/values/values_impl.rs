@@ -1379,6 +1379,16 @@ impl VMValueCast<Vec<u8>> for Value {
     }
 }
 
+impl VMValueCast<Vec<u32>> for Value {
+    fn cast(self) -> PartialVMResult<Vec<u32>> {
+        match self.0 {
+            ValueImpl::Container(Container::VecU32(r)) => take_unique_ownership(r),
+            v => Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR)
+                .with_message(format!("cannot cast {:?} to vector<u32>", v,))),
+        }
+    }
+}
+
 impl VMValueCast<Vec<u64>> for Value {
     fn cast(self) -> PartialVMResult<Vec<u64>> {
         match self.0 {
@@ -11,6 +11,7 @@ use crate::{
 };
 use anyhow::Result;
 use clap::Parser;
+use move_command_line_common::values::ParsedValue;
 use move_core_types::{
     errmap::ErrorMapping, language_storage::TypeTag, parser,
     transaction_argument::TransactionArgument,
@@ -84,6 +85,26 @@ pub enum SandboxCommand {
             num_args = 0..
         )]
         args: Vec<TransactionArgument>,
+        /// Possibly-empty list of arguments passed to the transaction, parsed against the parameter
+        /// types of `script_file` instead of through `args`. Use this for arguments `args` cannot
+        /// express, such as nested vectors (e.g., `vector[x"01",b"hi"]` for `vector<vector<u8>>`)
+        /// and structs (e.g., `0x2::M::S{f:1}`). Untyped numbers take the width of their parameter.
+        #[clap(
+            long = "values",
+            value_parser = ParsedValue::<()>::parse,
+            num_args = 0..,
+            conflicts_with = "args"
+        )]
+        values: Vec<ParsedValue>,
+        /// Possibly-empty list of arguments passed to the transaction, each already serialized
+        /// with BCS and given as a hexadecimal string (e.g., `x"0700000000000000"` for the `u64`
+        /// value 7). They are passed to the VM as-is, so they can be of any type, including structs.
+        #[clap(
+            long = "serialized-args",
+            num_args = 0..,
+            conflicts_with_all = ["args", "values"]
+        )]
+        serialized_args: Vec<String>,
         /// Possibly-empty list of type arguments passed to the transaction (e.g., `T` in
         /// `main<T>()`). Must match the type arguments kinds expected by `script_file`.
         #[clap(
@@ -224,6 +245,8 @@ impl SandboxCommand {
                 script_name,
                 signers,
                 args,
+                values,
+                serialized_args,
                 type_args,
                 gas_budget,
                 dry_run,
@@ -231,6 +254,27 @@ impl SandboxCommand {
                 let context =
                     PackageContext::new(&move_args.package_path, &move_args.build_config)?;
                 let state = context.prepare_state(bytecode_version, storage_dir)?;
+                if !serialized_args.is_empty() {
+                    return sandbox::commands::run_with_serialized_args(
+                        natives,
+                        cost_table,
+                        error_descriptions,
+                        &state,
+                        context.package(),
+                        script_file,
+                        script_name,
+                        signers,
+                        serialized_args
+                            .iter()
+                            .map(|arg| parse_serialized_arg(arg))
+                            .collect::<Result<_>>()?,
+                        type_args.to_vec(),
+                        *gas_budget,
+                        bytecode_version,
+                        *dry_run,
+                        move_args.verbose,
+                    );
+                }
                 sandbox::commands::run(
                     natives,
                     cost_table,
@@ -241,6 +285,7 @@ impl SandboxCommand {
                     script_name,
                     signers,
                     args,
+                    values,
                     type_args.to_vec(),
                     *gas_budget,
                     bytecode_version,
@@ -317,6 +362,13 @@ fn handle_generate_commands(cmd: &GenerateCommand, state: &OnDiskStateView) -> R
     }
 }
 
+fn parse_serialized_arg(s: &str) -> Result<Vec<u8>> {
+    match parser::parse_transaction_argument(s)? {
+        TransactionArgument::U8Vector(bytes) => Ok(bytes),
+        _ => anyhow::bail!("Expected a hexadecimal string (e.g., `x\"0700000000000000\"`)"),
+    }
+}
+
 #[test]
 fn verify_tool() {
     use clap::CommandFactory;
@@ -6,12 +6,17 @@ use crate::{
     sandbox::utils::{
         contains_module, explain_execution_effects, explain_execution_error, get_gas_status,
         is_bytecode_file, maybe_commit_effects, on_disk_state_view::OnDiskStateView,
+        serialize_values_as_args,
     },
     NativeFunctionRecord,
 };
 use anyhow::{anyhow, bail, Result};
-use move_binary_format::file_format::CompiledModule;
-use move_command_line_common::env::get_bytecode_version_from_env;
+use move_binary_format::{
+    access::{ModuleAccess, ScriptAccess},
+    binary_views::BinaryIndexedView,
+    file_format::{CompiledModule, CompiledScript},
+};
+use move_command_line_common::{env::get_bytecode_version_from_env, values::ParsedValue};
 use move_core_types::{
     account_address::AccountAddress,
     errmap::ErrorMapping,
@@ -39,12 +44,119 @@ pub fn run(
     script_name_opt: &Option<String>,
     signers: &[String],
     txn_args: &[TransactionArgument],
+    value_args: &[ParsedValue],
     vm_type_args: Vec<TypeTag>,
     gas_budget: Option<u64>,
     bytecode_version: Option<u32>,
     dry_run: bool,
     verbose: bool,
 ) -> Result<()> {
+    let bytecode = load_bytecode(state, package, script_path, bytecode_version)?;
+    let vm_args: Vec<Vec<u8>> = if value_args.is_empty() {
+        convert_txn_args(txn_args)
+    } else {
+        // Parse against the parameter types, which `TransactionArgument` cannot express in general.
+        let named_addresses = |name: &str| {
+            package
+                .compiled_package_info
+                .address_alias_instantiation
+                .iter()
+                .find(|(named, _)| named.as_str() == name)
+                .map(|(_, addr)| *addr)
+        };
+        match script_name_opt {
+            Some(script_name) => {
+                let module = CompiledModule::deserialize(&bytecode)
+                    .map_err(|e| anyhow!("Error deserializing module: {:?}", e))?;
+                let handle = module
+                    .function_defs()
+                    .iter()
+                    .map(|def| module.function_handle_at(def.function))
+                    .find(|handle| module.identifier_at(handle.name).as_str() == script_name)
+                    .ok_or_else(|| {
+                        anyhow!("Function {} not found in {}", script_name, module.self_id())
+                    })?;
+                serialize_values_as_args(
+                    state,
+                    &BinaryIndexedView::Module(&module),
+                    &module.signature_at(handle.parameters).0,
+                    value_args,
+                    &named_addresses,
+                )?
+            },
+            None => {
+                let script = CompiledScript::deserialize(&bytecode)
+                    .map_err(|e| anyhow!("Error deserializing script: {:?}", e))?;
+                serialize_values_as_args(
+                    state,
+                    &BinaryIndexedView::Script(&script),
+                    &script.signature_at(script.parameters).0,
+                    value_args,
+                    &named_addresses,
+                )?
+            },
+        }
+    };
+
+    execute(
+        natives,
+        cost_table,
+        error_descriptions,
+        state,
+        bytecode,
+        script_name_opt,
+        signers,
+        vm_args,
+        vm_type_args,
+        gas_budget,
+        dry_run,
+        verbose,
+    )
+}
+
+/// Like `run`, but takes the non-signer arguments already BCS-serialized, instead of converting
+/// them from `TransactionArgument`s. This can express any argument type, e.g., structs, which is
+/// useful to call functions from pre-generated fixtures.
+#[allow(clippy::too_many_arguments)]
+pub fn run_with_serialized_args(
+    natives: impl IntoIterator<Item = NativeFunctionRecord>,
+    cost_table: &CostTable,
+    error_descriptions: &ErrorMapping,
+    state: &OnDiskStateView,
+    package: &CompiledPackage,
+    script_path: &Path,
+    script_name_opt: &Option<String>,
+    signers: &[String],
+    serialized_args: Vec<Vec<u8>>,
+    vm_type_args: Vec<TypeTag>,
+    gas_budget: Option<u64>,
+    bytecode_version: Option<u32>,
+    dry_run: bool,
+    verbose: bool,
+) -> Result<()> {
+    let bytecode = load_bytecode(state, package, script_path, bytecode_version)?;
+    execute(
+        natives,
+        cost_table,
+        error_descriptions,
+        state,
+        bytecode,
+        script_name_opt,
+        signers,
+        serialized_args,
+        vm_type_args,
+        gas_budget,
+        dry_run,
+        verbose,
+    )
+}
+
+fn load_bytecode(
+    state: &OnDiskStateView,
+    package: &CompiledPackage,
+    script_path: &Path,
+    bytecode_version: Option<u32>,
+) -> Result<Vec<u8>> {
     if !script_path.exists() {
         bail!("Script file {:?} does not exist", script_path)
     };
@@ -71,20 +183,37 @@ move run` must be applied to a module inside `storage/`",
             None => bail!("Unable to find script in file {:?}", script_path),
         }
     };
+    Ok(bytecode)
+}
 
+/// Execute the script or the entry function `script_name_opt` of `bytecode`, prepending the
+/// signers to the serialized `vm_args`.
+#[allow(clippy::too_many_arguments)]
+fn execute(
+    natives: impl IntoIterator<Item = NativeFunctionRecord>,
+    cost_table: &CostTable,
+    error_descriptions: &ErrorMapping,
+    state: &OnDiskStateView,
+    bytecode: Vec<u8>,
+    script_name_opt: &Option<String>,
+    signers: &[String],
+    vm_args: Vec<Vec<u8>>,
+    vm_type_args: Vec<TypeTag>,
+    gas_budget: Option<u64>,
+    dry_run: bool,
+    verbose: bool,
+) -> Result<()> {
     let signer_addresses = signers
         .iter()
         .map(|s| AccountAddress::from_hex_literal(s))
         .collect::<Result<Vec<AccountAddress>, _>>()?;
-    // TODO: parse Value's directly instead of going through the indirection of TransactionArgument?
-    let vm_args: Vec<Vec<u8>> = convert_txn_args(txn_args);
 
     let vm = MoveVM::new(natives).unwrap();
     let mut gas_status = get_gas_status(cost_table, gas_budget)?;
     let mut session = vm.new_session(state);
 
-    let script_type_parameters = vec![];
-    let script_parameters = vec![];
+    // Counted before the signers are prepended, to explain argument count mismatches.
+    let num_args = vm_args.len();
     // TODO rethink move-cli arguments for executing functions
     let vm_args = signer_addresses
         .iter()
@@ -97,27 +226,52 @@ move run` must be applied to a module inside `storage/`",
         .collect();
 
     let storage = TraversalStorage::new();
-    let res = match script_name_opt {
+    // The type parameters and parameters of the function are only used to explain errors.
+    let (res, script_type_parameters, script_parameters) = match script_name_opt {
         Some(script_name) => {
             // script fun. parse module, extract script ID to pass to VM
             let module = CompiledModule::deserialize(&bytecode)
                 .map_err(|e| anyhow!("Error deserializing module: {:?}", e))?;
-            session.execute_entry_function(
+            let (type_parameters, parameters) = module
+                .function_defs()
+                .iter()
+                .map(|def| module.function_handle_at(def.function))
+                .find(|handle| module.identifier_at(handle.name).as_str() == script_name)
+                .map(|handle| {
+                    (
+                        handle.type_parameters.clone(),
+                        module.signature_at(handle.parameters).0.clone(),
+                    )
+                })
+                .unwrap_or_default();
+            let res = session.execute_entry_function(
                 &module.self_id(),
                 IdentStr::new(script_name)?,
                 vm_type_args.clone(),
                 vm_args,
                 &mut gas_status,
                 &mut TraversalContext::new(&storage),
-            )
+            );
+            (res, type_parameters, parameters)
+        },
+        None => {
+            let (type_parameters, parameters) = CompiledScript::deserialize(&bytecode)
+                .map(|script| {
+                    (
+                        script.type_parameters.clone(),
+                        script.signature_at(script.parameters).0.clone(),
+                    )
+                })
+                .unwrap_or_default();
+            let res = session.execute_script(
+                bytecode,
+                vm_type_args.clone(),
+                vm_args,
+                &mut gas_status,
+                &mut TraversalContext::new(&storage),
+            );
+            (res, type_parameters, parameters)
         },
-        None => session.execute_script(
-            bytecode.to_vec(),
-            vm_type_args.clone(),
-            vm_args,
-            &mut gas_status,
-            &mut TraversalContext::new(&storage),
-        ),
     };
 
     if let Err(err) = res {
@@ -129,7 +283,7 @@ move run` must be applied to a module inside `storage/`",
             &script_parameters,
             &vm_type_args,
             &signer_addresses,
-            txn_args,
+            num_args,
         )
     } else {
         let changeset = session.finish().map_err(|e| e.into_vm_status())?;
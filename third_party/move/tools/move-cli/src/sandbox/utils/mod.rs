@@ -2,18 +2,24 @@
 // Copyright (c) The Move Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 use colored::Colorize;
 use difference::{Changeset, Difference};
 use move_binary_format::{
     access::ModuleAccess,
+    binary_views::BinaryIndexedView,
     compatibility::Compatibility,
     errors::VMError,
-    file_format::{AbilitySet, CompiledModule, FunctionDefinitionIndex, SignatureToken},
+    file_format::{
+        AbilitySet, CompiledModule, FunctionDefinitionIndex, SignatureToken, StructFieldInformation,
+    },
     normalized, IndexKind,
 };
 use move_bytecode_utils::Modules;
-use move_command_line_common::files::{FileHash, MOVE_COMPILED_EXTENSION};
+use move_command_line_common::{
+    files::{FileHash, MOVE_COMPILED_EXTENSION},
+    values::ParsedValue,
+};
 use move_compiler::{
     compiled_unit::{CompiledUnit, NamedCompiledModule},
     diagnostics::{self, report_diagnostics, Diagnostic, Diagnostics, FileName},
@@ -23,7 +29,7 @@ use move_core_types::{
     effects::{ChangeSet, Op},
     errmap::ErrorMapping,
     language_storage::{ModuleId, TypeTag},
-    transaction_argument::TransactionArgument,
+    value::{MoveStruct, MoveValue},
     vm_status::{AbortLocation, StatusCode, VMStatus},
 };
 use move_ir_types::location::Loc;
@@ -258,7 +264,7 @@ pub(crate) fn maybe_commit_effects(
 pub(crate) fn explain_type_error(
     script_params: &[SignatureToken],
     signers: &[AccountAddress],
-    txn_args: &[TransactionArgument],
+    num_args: usize,
 ) {
     use SignatureToken::*;
     let expected_num_signers = script_params
@@ -280,12 +286,11 @@ pub(crate) fn explain_type_error(
 
     // TODO: printing type(s) of missing arguments could be useful
     let expected_num_args = script_params.len() - signers.len();
-    if expected_num_args != txn_args.len() {
+    if expected_num_args != num_args {
         println!(
             "Execution failed with incorrect number of arguments: script expected {:?}, but found \
              {:?}",
-            expected_num_args,
-            txn_args.len()
+            expected_num_args, num_args
         );
         return;
     }
@@ -295,6 +300,150 @@ pub(crate) fn explain_type_error(
     println!("Execution failed with type error when binding type arguments to type parameters")
 }
 
+/// Serialize the parsed `values` as the non-signer arguments of a function with parameter
+/// types `params`, which are declared in `view`. Struct parameters are resolved against the
+/// modules in `state`, and named addresses against `named_addresses`.
+pub(crate) fn serialize_values_as_args(
+    state: &OnDiskStateView,
+    view: &BinaryIndexedView,
+    params: &[SignatureToken],
+    values: &[ParsedValue],
+    named_addresses: &impl Fn(&str) -> Option<AccountAddress>,
+) -> Result<Vec<Vec<u8>>> {
+    // Signers come first and are passed separately.
+    let num_signers = params
+        .iter()
+        .take_while(|t| match t {
+            SignatureToken::Signer => true,
+            SignatureToken::Reference(r) => r.is_signer(),
+            _ => false,
+        })
+        .count();
+    let params = &params[num_signers..];
+    if params.len() != values.len() {
+        bail!(
+            "Expected {} arguments, but found {}",
+            params.len(),
+            values.len()
+        )
+    }
+    params
+        .iter()
+        .zip(values)
+        .map(|(ty, value)| {
+            let value = typed_move_value(state, view, ty, value.clone(), named_addresses)?;
+            value
+                .simple_serialize()
+                .ok_or_else(|| anyhow!("Failed to serialize argument {:?}", value))
+        })
+        .collect()
+}
+
+/// Convert a parsed value into a `MoveValue` of type `ty`, which is declared in `view`.
+fn typed_move_value(
+    state: &OnDiskStateView,
+    view: &BinaryIndexedView,
+    ty: &SignatureToken,
+    value: ParsedValue,
+    named_addresses: &impl Fn(&str) -> Option<AccountAddress>,
+) -> Result<MoveValue> {
+    use SignatureToken as T;
+    Ok(match (ty, value) {
+        (T::Bool, ParsedValue::Bool(b)) => MoveValue::Bool(b),
+        (T::U8, ParsedValue::U8(u)) => MoveValue::U8(u),
+        (T::U8, ParsedValue::InferredNum(u)) => MoveValue::U8(u.try_into()?),
+        (T::U16, ParsedValue::U16(u)) => MoveValue::U16(u),
+        (T::U16, ParsedValue::InferredNum(u)) => MoveValue::U16(u.try_into()?),
+        (T::U32, ParsedValue::U32(u)) => MoveValue::U32(u),
+        (T::U32, ParsedValue::InferredNum(u)) => MoveValue::U32(u.try_into()?),
+        (T::U64, ParsedValue::U64(u)) => MoveValue::U64(u),
+        (T::U64, ParsedValue::InferredNum(u)) => MoveValue::U64(u.try_into()?),
+        (T::U128, ParsedValue::U128(u)) => MoveValue::U128(u),
+        (T::U128, ParsedValue::InferredNum(u)) => MoveValue::U128(u.try_into()?),
+        (T::U256, ParsedValue::U256(u) | ParsedValue::InferredNum(u)) => MoveValue::U256(u),
+        (T::Address, ParsedValue::Address(a)) => {
+            MoveValue::Address(a.into_account_address(named_addresses)?)
+        },
+        (T::Vector(elem_ty), ParsedValue::Vector(elems)) => MoveValue::Vector(
+            elems
+                .into_iter()
+                .map(|elem| typed_move_value(state, view, elem_ty, elem, named_addresses))
+                .collect::<Result<_>>()?,
+        ),
+        (T::Struct(idx), ParsedValue::Struct(addr, module_name, struct_name, mut fields)) => {
+            let handle = view.struct_handle_at(*idx);
+            let module_id = view.module_id_for_handle(view.module_handle_at(handle.module));
+            let name = view.identifier_at(handle.name);
+            if addr.into_account_address(named_addresses)? != *module_id.address()
+                || module_name != module_id.name().as_str()
+                || struct_name != name.as_str()
+            {
+                bail!(
+                    "Expected a value of struct {}::{}, but found {}::{}",
+                    module_id,
+                    name,
+                    module_name,
+                    struct_name
+                )
+            }
+            let module = state
+                .view_compiled_module(&module_id)?
+                .ok_or_else(|| anyhow!("Module {} not found", module_id))?;
+            let def = module
+                .struct_defs()
+                .iter()
+                .find(|def| {
+                    module.identifier_at(module.struct_handle_at(def.struct_handle).name) == name
+                })
+                .ok_or_else(|| anyhow!("Struct {}::{} not found", module_id, name))?;
+            let field_defs = match &def.field_information {
+                StructFieldInformation::Declared(field_defs) => field_defs,
+                StructFieldInformation::Native => bail!(
+                    "Native struct {}::{} cannot be passed as an argument",
+                    module_id,
+                    name
+                ),
+            };
+            let module_view = BinaryIndexedView::Module(&module);
+            let values = field_defs
+                .iter()
+                .map(|field_def| {
+                    let field_name = module.identifier_at(field_def.name).as_str();
+                    let field = fields.remove(field_name).ok_or_else(|| {
+                        anyhow!(
+                            "Missing field `{}` of struct {}::{}",
+                            field_name,
+                            module_id,
+                            name
+                        )
+                    })?;
+                    typed_move_value(
+                        state,
+                        &module_view,
+                        &field_def.signature.0,
+                        field,
+                        named_addresses,
+                    )
+                })
+                .collect::<Result<_>>()?;
+            if let Some(field_name) = fields.keys().next() {
+                bail!(
+                    "Unknown field `{}` of struct {}::{}",
+                    field_name,
+                    module_id,
+                    name
+                )
+            }
+            MoveValue::Struct(MoveStruct::Runtime(values))
+        },
+        (ty, value) => bail!(
+            "Unsupported argument {:?} for parameter type {:?}",
+            value,
+            ty
+        ),
+    })
+}
+
 pub(crate) fn explain_publish_error(
     error: VMError,
     state: &OnDiskStateView,
@@ -453,7 +602,7 @@ pub(crate) fn explain_execution_error(
     script_parameters: &[SignatureToken],
     vm_type_args: &[TypeTag],
     signers: &[AccountAddress],
-    txn_args: &[TransactionArgument],
+    num_args: usize,
 ) -> Result<()> {
     use StatusCode::*;
     match error.into_vm_status() {
@@ -535,7 +684,7 @@ pub(crate) fn explain_execution_error(
         VMStatus::Error {
             status_code: TYPE_MISMATCH,
             ..
-        } => explain_type_error(script_parameters, signers, txn_args),
+        } => explain_type_error(script_parameters, signers, num_args),
         VMStatus::Error {
             status_code: LINKER_ERROR,
             ..
@@ -0,0 +1,9 @@
+script {
+    use 0x2::Point::{Self, Point};
+
+    fun main(_account: signer, p: Point, n: u64) {
+        assert!(Point::x(&p) == 1, 77);
+        assert!(Point::y(&p) == 2, 78);
+        assert!(n == 7, 79);
+    }
+}
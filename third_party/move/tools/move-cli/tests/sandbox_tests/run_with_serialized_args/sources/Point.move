@@ -0,0 +1,14 @@
+module 0x2::Point {
+    struct Point has drop {
+        x: u64,
+        y: u64,
+    }
+
+    public fun x(p: &Point): u64 {
+        p.x
+    }
+
+    public fun y(p: &Point): u64 {
+        p.y
+    }
+}
@@ -0,0 +1,6 @@
+script {
+    fun main(_account: signer, v: vector<vector<u8>>, n: u8) {
+        assert!(v == vector[x"0102", b"hi"], 77);
+        assert!(n == 7, 78);
+    }
+}
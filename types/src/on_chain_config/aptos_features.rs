@@ -75,6 +75,22 @@ pub enum FeatureFlag {
     PRIMARY_APT_FUNGIBLE_STORE_AT_USER_ADDRESS = 61,
     OBJECT_NATIVE_DERIVED_ADDRESS = 62,
     DISPATCHABLE_FUNGIBLE_ASSET = 63,
+    SECP256K1_SECP256R1_STRUCTURES = 64,
+    GRUMPKIN_STRUCTURES = 65,
+    PASTA_STRUCTURES = 66,
+    KECCAK_TO_BN254_FR_NATIVE = 67,
+    GROTH16_BN254_NATIVE = 68,
+    KZG_BN254_NATIVE = 69,
+    MODEXP_NATIVE = 70,
+    BLS12_381_BASE_FIELD_STRUCTURES = 71,
+    SHA256_COMPRESS_NATIVE = 72,
+    ALGEBRA_DESERIALIZE_CHECKED_NATIVE = 73,
+    BN254_FQ12_CYCLOTOMIC_POW_NATIVE = 74,
+    BN254_MODULI_NATIVE = 75,
+    BN254_FR_BYTES_NATIVE = 76,
+    BN254_FR_FROM_TRANSCRIPT_NATIVE = 77,
+    ALGEBRA_IS_ZERO_IS_ONE_NATIVES = 78,
+    ALGEBRA_POW_U64_NATIVE = 79,
 }
 
 impl FeatureFlag {